@@ -11,7 +11,7 @@ fn main() {
     }
 
     cc::Build::new()
-        .file("odpi/embed/dpi.c")
+        // dpi-ext.c includes odpi/embed/dpi.c.
         .file("odpi-ext/dpi-ext.c")
         .include("odpi/include")
         .include("odpi/src")
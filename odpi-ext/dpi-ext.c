@@ -1,15 +1,11 @@
-#include "dpiImpl.h"
+/* ODPI-C is embedded here to use its static functions such as dpiOci__loadSymbol(). */
+#include "../odpi/embed/dpi.c"
 #include "dpi-ext.h"
 
 #define OCI_ATTR_SQLFNCODE 10
-
-/* Copied from dpiConn.c */
-static int dpiConn__check(dpiConn *conn, const char *fnName, dpiError *error)
-{
-    if (dpiGen__startPublicFn(conn, DPI_HTYPE_CONN, fnName, error) < 0)
-        return DPI_FAILURE;
-    return dpiConn__checkConnected(conn, error);
-}
+#define OCI_PREP2_CACHE_SEARCHONLY 0x0010
+/* ORA-24431: Statement does not exist in the cache */
+#define ORA_STMT_NOT_IN_CACHE 24431
 
 int dpi_ext_dpiStmt_getFnCode(dpiStmt *stmt, uint16_t *sqlfncode)
 {
@@ -35,3 +31,76 @@ int dpi_ext_dpiConn_getServerStatus(dpiConn *conn, uint32_t *server_status)
     return dpiGen__endPublicFn(conn, status, &error);
 }
 
+/* Searches the statement cache without preparing a new statement on a cache miss. */
+static int dpi_ext__stmtPrepare2SearchOnly(dpiConn *conn, const char *sql,
+        uint32_t sqlLength, const char *tag, uint32_t tagLength, void **handle,
+        int *found, dpiError *error)
+{
+    int status;
+
+    DPI_OCI_LOAD_SYMBOL("OCIStmtPrepare2", dpiOciSymbols.fnStmtPrepare2)
+    DPI_OCI_ENSURE_ERROR_HANDLE(error)
+    status = (*dpiOciSymbols.fnStmtPrepare2)(conn->handle, handle,
+            error->handle, sql, sqlLength, tag, tagLength, DPI_OCI_NTV_SYNTAX,
+            OCI_PREP2_CACHE_SEARCHONLY);
+    if (DPI_OCI_ERROR_OCCURRED(status)) {
+        *handle = NULL;
+        dpiError__setFromOCI(error, status, conn, "search statement cache");
+        if (error->buffer->code != ORA_STMT_NOT_IN_CACHE)
+            return DPI_FAILURE;
+        *found = 0;
+        return DPI_SUCCESS;
+    }
+    *found = 1;
+    return DPI_SUCCESS;
+}
+
+/* Puts the statement found by dpi_ext__stmtPrepare2SearchOnly() back into the cache. */
+static int dpi_ext__stmtRelease(dpiConn *conn, void *handle, const char *tag,
+        uint32_t tagLength, dpiError *error)
+{
+    int status;
+
+    DPI_OCI_LOAD_SYMBOL("OCIStmtRelease", dpiOciSymbols.fnStmtRelease)
+    DPI_OCI_ENSURE_ERROR_HANDLE(error)
+    status = (*dpiOciSymbols.fnStmtRelease)(handle, error->handle, tag,
+            tagLength, DPI_OCI_DEFAULT);
+    if (DPI_OCI_ERROR_OCCURRED(status))
+        return dpiError__setFromOCI(error, status, conn, "release statement");
+    return DPI_SUCCESS;
+}
+
+int dpi_ext_dpiConn_findStmtInCache(dpiConn *conn, const char *sql,
+        uint32_t sqlLength, const char *tag, uint32_t tagLength, int *found)
+{
+    uint32_t cacheSize = 0;
+    void *handle = NULL;
+    dpiError error;
+    int status;
+
+    *found = 0;
+    if (dpiConn__check(conn, __func__, &error) < 0)
+        return dpiGen__endPublicFn(conn, DPI_FAILURE, &error);
+    if (dpiOci__attrGet(conn->handle, DPI_OCI_HTYPE_SVCCTX, &cacheSize, NULL,
+            DPI_OCI_ATTR_STMTCACHESIZE, "get stmt cache size", &error) < 0)
+        return dpiGen__endPublicFn(conn, DPI_FAILURE, &error);
+    /* The cache cannot be searched when it is disabled. */
+    if (cacheSize == 0)
+        return dpiGen__endPublicFn(conn, DPI_SUCCESS, &error);
+    status = dpi_ext__stmtPrepare2SearchOnly(conn, sql, sqlLength, tag,
+            tagLength, &handle, found, &error);
+    if (status == DPI_SUCCESS && *found)
+        status = dpi_ext__stmtRelease(conn, handle, tag, tagLength, &error);
+    return dpiGen__endPublicFn(conn, status, &error);
+}
+
+int dpi_ext_dpiStmt_deleteFromCache(dpiStmt *stmt)
+{
+    dpiError error;
+
+    if (dpiGen__startPublicFn(stmt, DPI_HTYPE_STMT, __func__, &error) < 0)
+        return dpiGen__endPublicFn(stmt, DPI_FAILURE, &error);
+    /* dpiStmt_close() releases the statement with OCI_STRLS_CACHE_DELETE. */
+    stmt->deleteFromCache = 1;
+    return dpiGen__endPublicFn(stmt, DPI_SUCCESS, &error);
+}
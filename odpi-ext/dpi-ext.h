@@ -9,5 +9,8 @@
 
 int dpi_ext_dpiStmt_getFnCode(dpiStmt *stmt, uint16_t *sqlfncode);
 int dpi_ext_dpiConn_getServerStatus(dpiConn *conn, uint32_t *status);
+int dpi_ext_dpiConn_findStmtInCache(dpiConn *conn, const char *sql,
+        uint32_t sqlLength, const char *tag, uint32_t tagLength, int *found);
+int dpi_ext_dpiStmt_deleteFromCache(dpiStmt *stmt);
 
 #endif
@@ -0,0 +1,83 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use syn::Attribute;
+use syn::Lit;
+use syn::LitStr;
+use syn::Meta;
+use syn::NestedMeta;
+
+/// Parameters in `#[oracle(name = "value", ...)]` attributes.
+pub struct Attrs {
+    items: Vec<(String, LitStr)>,
+}
+
+impl Attrs {
+    /// Collects `#[oracle(...)]` parameters. Names not in `allowed` are
+    /// reported as errors.
+    pub fn parse(attrs: &[Attribute], allowed: &[&str]) -> syn::Result<Attrs> {
+        let mut items: Vec<(String, LitStr)> = Vec::new();
+        for attr in attrs {
+            if !attr.path.is_ident("oracle") {
+                continue;
+            }
+            let list = match attr.parse_meta()? {
+                Meta::List(list) => list,
+                meta => {
+                    return Err(syn::Error::new_spanned(
+                        meta,
+                        "expected #[oracle(name = \"value\")]",
+                    ))
+                }
+            };
+            for nested in list.nested {
+                let nv = match nested {
+                    NestedMeta::Meta(Meta::NameValue(nv)) => nv,
+                    nested => {
+                        return Err(syn::Error::new_spanned(nested, "expected name = \"value\""))
+                    }
+                };
+                let name = match nv.path.get_ident() {
+                    Some(ident) => ident.to_string(),
+                    None => return Err(syn::Error::new_spanned(nv.path, "expected identifier")),
+                };
+                if !allowed.contains(&name.as_str()) {
+                    return Err(syn::Error::new_spanned(
+                        nv.path,
+                        format!("unknown oracle attribute: {}", name),
+                    ));
+                }
+                if items.iter().any(|item| item.0 == name) {
+                    return Err(syn::Error::new_spanned(
+                        nv.path,
+                        format!("duplicate oracle attribute: {}", name),
+                    ));
+                }
+                match nv.lit {
+                    Lit::Str(value) => items.push((name, value)),
+                    lit => return Err(syn::Error::new_spanned(lit, "expected string literal")),
+                }
+            }
+        }
+        Ok(Attrs { items })
+    }
+
+    pub fn get(&self, name: &str) -> Option<&LitStr> {
+        self.items
+            .iter()
+            .find(|item| item.0 == name)
+            .map(|item| &item.1)
+    }
+}
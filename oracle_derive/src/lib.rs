@@ -0,0 +1,178 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+//! Derive macros for [rust-oracle](https://crates.io/crates/oracle).
+//!
+//! Don't use this crate directly. Enable the `derive` feature of the
+//! `oracle` crate and use macros re-exported in `oracle::sql_type`.
+
+extern crate proc_macro;
+
+mod attr;
+mod object;
+mod params;
+mod sql;
+
+use proc_macro::TokenStream;
+use syn::parse_macro_input;
+use syn::DeriveInput;
+
+fn to_token_stream(result: syn::Result<proc_macro2::TokenStream>) -> TokenStream {
+    match result {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Derives [`FromSql`] for newtypes and unit enums.
+///
+/// A struct with one field is converted by the field's type.
+///
+/// A unit enum is converted from a string when at least one variant
+/// has `#[oracle(rename = "...")]`. The variant name is used as the code
+/// of variants without `rename`. Otherwise the enum is converted from an
+/// integer using the discriminant values. An unknown code is reported as
+/// `Error::InvalidTypeConversion`.
+///
+/// ```ignore
+/// use oracle::sql_type::FromSql;
+///
+/// #[derive(FromSql)]
+/// struct CustomerId(i64);
+///
+/// #[derive(FromSql)]
+/// enum Status {
+///     #[oracle(rename = "A")]
+///     Active,
+///     #[oracle(rename = "I")]
+///     Inactive,
+/// }
+///
+/// #[derive(FromSql)]
+/// enum Priority {
+///     Low = 1,
+///     Middle, // 2
+///     High,   // 3
+/// }
+/// ```
+///
+/// [`FromSql`]: https://docs.rs/oracle/*/oracle/sql_type/trait.FromSql.html
+#[proc_macro_derive(FromSql, attributes(oracle))]
+pub fn derive_from_sql(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    to_token_stream(sql::derive_from_sql(&input))
+}
+
+/// Derives [`ToSql`] for newtypes and unit enums.
+///
+/// The Oracle type and value are those of the field for a struct with
+/// one field. A unit enum is bound as a string or an integer by the same
+/// rules as [`FromSql`](derive.FromSql.html).
+///
+/// [`ToSql`]: https://docs.rs/oracle/*/oracle/sql_type/trait.ToSql.html
+#[proc_macro_derive(ToSql, attributes(oracle))]
+pub fn derive_to_sql(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    to_token_stream(sql::derive_to_sql(&input))
+}
+
+/// Derives [`ToSqlNull`] for newtypes and unit enums.
+///
+/// This is required to bind `None` of `Option<T>`.
+///
+/// [`ToSqlNull`]: https://docs.rs/oracle/*/oracle/sql_type/trait.ToSqlNull.html
+#[proc_macro_derive(ToSqlNull, attributes(oracle))]
+pub fn derive_to_sql_null(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    to_token_stream(sql::derive_to_sql_null(&input))
+}
+
+/// Derives [`FromSql`], [`ToSql`] and [`ToSqlNull`] for a struct mapped to
+/// an Oracle object type.
+///
+/// The object type name is specified by `#[oracle(type_name = "...")]`.
+/// Each field is mapped to the attribute whose name is the upper-cased
+/// field name or the name specified by `#[oracle(rename = "...")]`.
+/// A field of `Vec<T>` other than `Vec<u8>` is mapped to a collection
+/// attribute whose elements are converted to or from `T`. A field whose
+/// type derives `OracleObject` is mapped to a nested object attribute.
+///
+/// Attribute names are checked against [`ObjectType::attributes()`] when
+/// the struct is fetched first. Attribute types are also checked when it
+/// is bound first.
+///
+/// ```ignore
+/// use oracle::sql_type::OracleObject;
+///
+/// // create type udt_address as object (street varchar2(60), city varchar2(30));
+/// #[derive(OracleObject)]
+/// #[oracle(type_name = "UDT_ADDRESS")]
+/// struct Address {
+///     street: String,
+///     city: String,
+/// }
+///
+/// // create type udt_phones as varray(10) of varchar2(20);
+/// // create type udt_person as object (
+/// //   id number(9), full_name varchar2(60), address udt_address, phones udt_phones);
+/// #[derive(OracleObject)]
+/// #[oracle(type_name = "UDT_PERSON")]
+/// struct Person {
+///     id: i32,
+///     #[oracle(rename = "FULL_NAME")]
+///     name: String,
+///     address: Option<Address>,
+///     phones: Vec<String>,
+/// }
+/// ```
+///
+/// [`FromSql`]: https://docs.rs/oracle/*/oracle/sql_type/trait.FromSql.html
+/// [`ToSql`]: https://docs.rs/oracle/*/oracle/sql_type/trait.ToSql.html
+/// [`ToSqlNull`]: https://docs.rs/oracle/*/oracle/sql_type/trait.ToSqlNull.html
+/// [`ObjectType::attributes()`]: https://docs.rs/oracle/*/oracle/sql_type/struct.ObjectType.html#method.attributes
+#[proc_macro_derive(OracleObject, attributes(oracle))]
+pub fn derive_oracle_object(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    to_token_stream(object::derive_oracle_object(&input))
+}
+
+/// Derives [`BindParams`] for a struct whose fields are bound as named
+/// parameters.
+///
+/// Each field is bound to the bind variable whose name is the field name
+/// or the name specified by `#[oracle(rename = "...")]`. Bind variable names
+/// are compared case-insensitively.
+///
+/// ```ignore
+/// use oracle::BindParams;
+///
+/// #[derive(BindParams)]
+/// struct EmpCondition {
+///     deptno: i32,
+///     #[oracle(rename = "min_sal")]
+///     sal: f64,
+/// }
+///
+/// let sql = "select ename from emp where deptno = :deptno and sal >= :min_sal";
+/// let cond = EmpCondition { deptno: 10, sal: 1000.0 };
+/// let enames = conn.statement(sql).query::<String, _>(&cond)?;
+/// ```
+///
+/// [`BindParams`]: https://docs.rs/oracle/*/oracle/trait.BindParams.html
+#[proc_macro_derive(BindParams, attributes(oracle))]
+pub fn derive_bind_params(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    to_token_stream(params::derive_bind_params(&input))
+}
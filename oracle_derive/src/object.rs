@@ -0,0 +1,230 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use crate::attr::Attrs;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Data;
+use syn::DeriveInput;
+use syn::Fields;
+use syn::GenericArgument;
+use syn::Ident;
+use syn::LitStr;
+use syn::PathArguments;
+use syn::Type;
+
+/// A field mapped to an attribute of an Oracle object type
+struct Attr<'a> {
+    ident: &'a Ident,
+    ty: &'a Type,
+    /// attribute name in the Oracle object type
+    name: LitStr,
+    /// element type when the field is `Vec<T>` other than `Vec<u8>`
+    elem_ty: Option<&'a Type>,
+}
+
+/// Returns `T` if `ty` is `Vec<T>` and `T` isn't `u8`.
+/// `Vec<u8>` is mapped to raw data types as other `FromSql` types.
+fn collection_element(ty: &Type) -> Option<&Type> {
+    match generic_argument(ty, "Vec") {
+        Some(Type::Path(ref elem)) if elem.path.is_ident("u8") => None,
+        elem => elem,
+    }
+}
+
+/// Returns `T` if `ty` is `Option<T>`. Otherwise, returns `ty`.
+/// `FromSql` for `Option<T>` accepts null values without checking types.
+fn option_inner(ty: &Type) -> &Type {
+    generic_argument(ty, "Option").unwrap_or(ty)
+}
+
+/// Returns `T` if `ty` is `name<T>`.
+fn generic_argument<'a>(ty: &'a Type, name: &str) -> Option<&'a Type> {
+    let path = match *ty {
+        Type::Path(ref path) if path.qself.is_none() => &path.path,
+        _ => return None,
+    };
+    let segment = path.segments.last()?;
+    if segment.ident != name {
+        return None;
+    }
+    let args = match segment.arguments {
+        PathArguments::AngleBracketed(ref args) if args.args.len() == 1 => args,
+        _ => return None,
+    };
+    match args.args.first() {
+        Some(GenericArgument::Type(ref ty)) => Some(ty),
+        _ => None,
+    }
+}
+
+pub fn derive_oracle_object(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let name_str = name.to_string();
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "generic structs are not supported",
+        ));
+    }
+    let attrs = Attrs::parse(&input.attrs, &["type_name"])?;
+    let type_name = match attrs.get("type_name") {
+        Some(type_name) => type_name.clone(),
+        None => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "#[oracle(type_name = \"...\")] is required",
+            ))
+        }
+    };
+    let fields = match input.data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "struct must have named fields",
+                ))
+            }
+        },
+        _ => return Err(syn::Error::new_spanned(name, "only structs are supported")),
+    };
+    let mut object_attrs = Vec::new();
+    for field in fields {
+        let ident = field.ident.as_ref().unwrap();
+        let attrs = Attrs::parse(&field.attrs, &["rename"])?;
+        let attr_name = match attrs.get("rename") {
+            Some(rename) => rename.clone(),
+            None => LitStr::new(&ident.to_string().to_uppercase(), ident.span()),
+        };
+        object_attrs.push(Attr {
+            ident,
+            ty: &field.ty,
+            name: attr_name,
+            elem_ty: collection_element(&field.ty),
+        });
+    }
+
+    let from_sql_checks = object_attrs.iter().map(|attr| {
+        let attr_name = &attr.name;
+        let (ty, is_collection) = match attr.elem_ty {
+            Some(elem_ty) => (option_inner(elem_ty), true),
+            None => (option_inner(attr.ty), false),
+        };
+        let rust_type = attr.ty;
+        quote! {
+            ::oracle::sql_type::check_attr_from_sql::<#ty>(
+                objtype,
+                #attr_name,
+                stringify!(#rust_type),
+                #is_collection,
+            )?;
+        }
+    });
+    let getters = object_attrs.iter().map(|attr| {
+        let ident = attr.ident;
+        let attr_name = &attr.name;
+        match attr.elem_ty {
+            Some(elem_ty) => quote!(#ident: obj.get_vec::<#elem_ty>(#attr_name)?),
+            None => {
+                let ty = attr.ty;
+                quote!(#ident: obj.get::<#ty>(#attr_name)?)
+            }
+        }
+    });
+    let setters = object_attrs.iter().map(|attr| {
+        let ident = attr.ident;
+        let attr_name = &attr.name;
+        match attr.elem_ty {
+            Some(_) => quote!(obj.set_vec(#attr_name, &self.#ident)?;),
+            None => quote!(obj.set(#attr_name, &self.#ident)?;),
+        }
+    });
+    let attr_defs = object_attrs.iter().map(|attr| {
+        let attr_name = &attr.name;
+        let (ty, is_collection) = match attr.elem_ty {
+            Some(elem_ty) => (elem_ty, true),
+            None => (attr.ty, false),
+        };
+        let rust_type = attr.ty;
+        quote! {
+            ::oracle::sql_type::ObjectAttrDef {
+                name: #attr_name,
+                rust_type: stringify!(#rust_type),
+                oratype: <#ty as ::oracle::sql_type::ToSqlNull>::oratype_for_null(conn)?,
+                is_collection: #is_collection,
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl ::oracle::sql_type::FromSql for #name {
+            fn from_sql(val: &::oracle::SqlValue) -> ::oracle::Result<Self> {
+                let obj = val.get::<::oracle::sql_type::Object>()?;
+                let objtype = obj.object_type();
+                ::oracle::sql_type::check_object_attrs(
+                    objtype,
+                    concat!(module_path!(), "::", #name_str, " as FromSql"),
+                    || {
+                        ::oracle::sql_type::check_object_type_name(objtype, #type_name, #name_str)?;
+                        #(#from_sql_checks)*
+                        Ok(())
+                    },
+                )?;
+                Ok(#name {
+                    #(#getters,)*
+                })
+            }
+        }
+
+        impl ::oracle::sql_type::ToSqlNull for #name {
+            fn oratype_for_null(
+                conn: &::oracle::Connection,
+            ) -> ::oracle::Result<::oracle::sql_type::OracleType> {
+                let objtype = conn.object_type(#type_name)?;
+                ::oracle::sql_type::check_object_attrs(
+                    &objtype,
+                    concat!(module_path!(), "::", #name_str, " as ToSql"),
+                    || ::oracle::sql_type::check_attr_types(&objtype, vec![#(#attr_defs),*]),
+                )?;
+                Ok(::oracle::sql_type::OracleType::Object(objtype))
+            }
+        }
+
+        impl ::oracle::sql_type::ToSql for #name {
+            fn oratype(
+                &self,
+                conn: &::oracle::Connection,
+            ) -> ::oracle::Result<::oracle::sql_type::OracleType> {
+                <#name as ::oracle::sql_type::ToSqlNull>::oratype_for_null(conn)
+            }
+
+            fn to_sql(&self, val: &mut ::oracle::SqlValue) -> ::oracle::Result<()> {
+                let objtype = match *val.oracle_type()? {
+                    ::oracle::sql_type::OracleType::Object(ref objtype) => objtype.clone(),
+                    ref oratype => {
+                        return Err(::oracle::Error::InvalidTypeConversion(
+                            #name_str.to_string(),
+                            oratype.to_string(),
+                        ))
+                    }
+                };
+                let mut obj = objtype.new_object()?;
+                #(#setters)*
+                val.set(&obj)
+            }
+        }
+    })
+}
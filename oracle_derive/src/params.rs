@@ -0,0 +1,59 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use crate::attr::Attrs;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Data;
+use syn::DeriveInput;
+use syn::Fields;
+use syn::LitStr;
+
+pub fn derive_bind_params(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    Attrs::parse(&input.attrs, &[])?;
+    let fields = match input.data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "struct must have named fields",
+                ))
+            }
+        },
+        _ => return Err(syn::Error::new_spanned(name, "only structs are supported")),
+    };
+    let mut binders = Vec::new();
+    for field in fields {
+        let ident = field.ident.as_ref().unwrap();
+        let attrs = Attrs::parse(&field.attrs, &["rename"])?;
+        let bind_name = match attrs.get("rename") {
+            Some(rename) => rename.clone(),
+            None => LitStr::new(&ident.to_string(), ident.span()),
+        };
+        binders.push(quote!(stmt.bind(#bind_name, &self.#ident)?;));
+    }
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::oracle::BindParams for #name #ty_generics #where_clause {
+            fn bind(&self, stmt: &mut ::oracle::Statement) -> ::oracle::Result<()> {
+                #(#binders)*
+                Ok(())
+            }
+        }
+    })
+}
@@ -0,0 +1,256 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use crate::attr::Attrs;
+use proc_macro2::Literal;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse_quote;
+use syn::Data;
+use syn::DeriveInput;
+use syn::Expr;
+use syn::Fields;
+use syn::Generics;
+use syn::Ident;
+use syn::LitStr;
+use syn::Member;
+use syn::Type;
+
+/// Shapes of types which `FromSql`, `ToSql` and `ToSqlNull` can be derived for.
+enum Shape {
+    /// A struct with one field. Conversions are delegated to the field.
+    Newtype { member: Member, ty: Box<Type> },
+    /// A unit enum stored as strings.
+    StrEnum {
+        idents: Vec<Ident>,
+        codes: Vec<LitStr>,
+    },
+    /// A unit enum stored as integers.
+    IntEnum {
+        idents: Vec<Ident>,
+        codes: Vec<TokenStream>,
+    },
+}
+
+impl Shape {
+    fn new(input: &DeriveInput) -> syn::Result<Shape> {
+        match input.data {
+            Data::Struct(ref data) => {
+                let mut iter = data.fields.iter();
+                match (iter.next(), iter.next()) {
+                    (Some(field), None) => Ok(Shape::Newtype {
+                        member: match field.ident {
+                            Some(ref ident) => Member::Named(ident.clone()),
+                            None => Member::Unnamed(0.into()),
+                        },
+                        ty: Box::new(field.ty.clone()),
+                    }),
+                    _ => Err(syn::Error::new_spanned(
+                        &input.ident,
+                        "struct must have exactly one field",
+                    )),
+                }
+            }
+            Data::Enum(ref data) => {
+                let mut idents = Vec::new();
+                let mut renames = Vec::new();
+                for variant in &data.variants {
+                    match variant.fields {
+                        Fields::Unit => (),
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                variant,
+                                "enum variants must not have fields",
+                            ))
+                        }
+                    }
+                    let attrs = Attrs::parse(&variant.attrs, &["rename"])?;
+                    idents.push(variant.ident.clone());
+                    renames.push(attrs.get("rename").cloned());
+                }
+                if idents.is_empty() {
+                    return Err(syn::Error::new_spanned(
+                        &input.ident,
+                        "enum must have at least one variant",
+                    ));
+                }
+                if renames.iter().any(Option::is_some) {
+                    // The variant name is used when `rename` isn't specified.
+                    let codes = renames
+                        .into_iter()
+                        .zip(idents.iter())
+                        .map(|(rename, ident)| {
+                            rename.unwrap_or_else(|| LitStr::new(&ident.to_string(), ident.span()))
+                        })
+                        .collect();
+                    Ok(Shape::StrEnum { idents, codes })
+                } else {
+                    // Discriminants are numbered in the same way as rustc does.
+                    let mut base: Option<&Expr> = None;
+                    let mut offset = 0i64;
+                    let mut codes = Vec::new();
+                    for variant in &data.variants {
+                        if let Some((_, ref expr)) = variant.discriminant {
+                            base = Some(expr);
+                            offset = 0;
+                        }
+                        let lit = Literal::i64_unsuffixed(offset);
+                        codes.push(match base {
+                            Some(expr) if offset == 0 => quote!((#expr) as i64),
+                            Some(expr) => quote!((#expr) as i64 + #lit),
+                            None => quote!(#lit),
+                        });
+                        offset += 1;
+                    }
+                    Ok(Shape::IntEnum { idents, codes })
+                }
+            }
+            Data::Union(_) => Err(syn::Error::new_spanned(
+                &input.ident,
+                "unions are not supported",
+            )),
+        }
+    }
+
+    /// Adds `T: bound` for the inner type of a newtype.
+    fn generics(&self, generics: &Generics, bound: TokenStream) -> Generics {
+        let mut generics = generics.clone();
+        if let Shape::Newtype { ref ty, .. } = *self {
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote!(#ty: #bound));
+        }
+        generics
+    }
+}
+
+pub fn derive_from_sql(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let shape = Shape::new(input)?;
+    let generics = shape.generics(&input.generics, quote!(::oracle::sql_type::FromSql));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let name_str = name.to_string();
+    let body = match shape {
+        Shape::Newtype { ref member, ref ty } => quote! {
+            Ok(#name {
+                #member: <#ty as ::oracle::sql_type::FromSql>::from_sql(val)?,
+            })
+        },
+        Shape::StrEnum {
+            ref idents,
+            ref codes,
+        } => quote! {
+            let code = <String as ::oracle::sql_type::FromSql>::from_sql(val)?;
+            match code.as_str() {
+                #( #codes => Ok(#name::#idents), )*
+                _ => Err(::oracle::Error::InvalidTypeConversion(
+                    format!("{:?}", code),
+                    #name_str.to_string(),
+                )),
+            }
+        },
+        Shape::IntEnum {
+            ref idents,
+            ref codes,
+        } => quote! {
+            let code = <i64 as ::oracle::sql_type::FromSql>::from_sql(val)?;
+            #(
+                if code == #codes {
+                    return Ok(#name::#idents);
+                }
+            )*
+            Err(::oracle::Error::InvalidTypeConversion(
+                code.to_string(),
+                #name_str.to_string(),
+            ))
+        },
+    };
+    Ok(quote! {
+        impl #impl_generics ::oracle::sql_type::FromSql for #name #ty_generics #where_clause {
+            fn from_sql(val: &::oracle::SqlValue) -> ::oracle::Result<Self> {
+                #body
+            }
+        }
+    })
+}
+
+pub fn derive_to_sql(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let shape = Shape::new(input)?;
+    let generics = shape.generics(&input.generics, quote!(::oracle::sql_type::ToSql));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    // `code` is a reference to a value whose type implements ToSql.
+    let code = match shape {
+        Shape::Newtype { ref member, .. } => quote! {
+            let code = &self.#member;
+        },
+        Shape::StrEnum {
+            ref idents,
+            ref codes,
+        } => quote! {
+            let code: &str = match *self {
+                #( #name::#idents => #codes, )*
+            };
+            let code = &code;
+        },
+        Shape::IntEnum {
+            ref idents,
+            ref codes,
+        } => quote! {
+            let code: i64 = match *self {
+                #( #name::#idents => #codes, )*
+            };
+            let code = &code;
+        },
+    };
+    Ok(quote! {
+        impl #impl_generics ::oracle::sql_type::ToSql for #name #ty_generics #where_clause {
+            fn oratype(
+                &self,
+                conn: &::oracle::Connection,
+            ) -> ::oracle::Result<::oracle::sql_type::OracleType> {
+                #code
+                ::oracle::sql_type::ToSql::oratype(code, conn)
+            }
+
+            fn to_sql(&self, val: &mut ::oracle::SqlValue) -> ::oracle::Result<()> {
+                #code
+                ::oracle::sql_type::ToSql::to_sql(code, val)
+            }
+        }
+    })
+}
+
+pub fn derive_to_sql_null(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let shape = Shape::new(input)?;
+    let generics = shape.generics(&input.generics, quote!(::oracle::sql_type::ToSqlNull));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let ty: Type = match shape {
+        Shape::Newtype { ty, .. } => *ty,
+        Shape::StrEnum { .. } => parse_quote!(&str),
+        Shape::IntEnum { .. } => parse_quote!(i64),
+    };
+    Ok(quote! {
+        impl #impl_generics ::oracle::sql_type::ToSqlNull for #name #ty_generics #where_clause {
+            fn oratype_for_null(
+                conn: &::oracle::Connection,
+            ) -> ::oracle::Result<::oracle::sql_type::OracleType> {
+                <#ty as ::oracle::sql_type::ToSqlNull>::oratype_for_null(conn)
+            }
+        }
+    })
+}
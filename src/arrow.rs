@@ -0,0 +1,773 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use std::cmp;
+use std::sync::Arc;
+
+use crate::binding::DPI_MAX_INT64_PRECISION;
+use crate::sql_type::OracleNumber;
+use crate::sql_type::OracleType;
+use crate::sql_type::Timestamp;
+use crate::Connection;
+use crate::DbError;
+use crate::Error;
+use crate::Result;
+use crate::ResultSet;
+use crate::RowValue;
+use crate::SqlValue;
+use arrow::array::Array;
+use arrow::array::ArrayRef;
+use arrow::array::AsArray;
+use arrow::array::BinaryArray;
+use arrow::array::BooleanArray;
+use arrow::array::Decimal128Array;
+use arrow::array::Float32Array;
+use arrow::array::Float64Array;
+use arrow::array::Int64Array;
+use arrow::array::StringArray;
+use arrow::array::TimestampMicrosecondArray;
+use arrow::array::TimestampMillisecondArray;
+use arrow::array::TimestampNanosecondArray;
+use arrow::array::TimestampSecondArray;
+use arrow::array::UInt64Array;
+use arrow::datatypes::DataType;
+use arrow::datatypes::Date32Type;
+use arrow::datatypes::Date64Type;
+use arrow::datatypes::Decimal128Type;
+use arrow::datatypes::Field;
+use arrow::datatypes::Float32Type;
+use arrow::datatypes::Float64Type;
+use arrow::datatypes::Int16Type;
+use arrow::datatypes::Int32Type;
+use arrow::datatypes::Int64Type;
+use arrow::datatypes::Int8Type;
+use arrow::datatypes::Schema;
+use arrow::datatypes::SchemaRef;
+use arrow::datatypes::TimeUnit;
+use arrow::datatypes::TimestampMicrosecondType;
+use arrow::datatypes::TimestampMillisecondType;
+use arrow::datatypes::TimestampNanosecondType;
+use arrow::datatypes::TimestampSecondType;
+use arrow::datatypes::UInt16Type;
+use arrow::datatypes::UInt32Type;
+use arrow::datatypes::UInt64Type;
+use arrow::datatypes::UInt8Type;
+use arrow::error::ArrowError;
+use arrow::record_batch::RecordBatch;
+
+/// The time zone of timestamp columns converted from `TIMESTAMP WITH TIME ZONE`
+/// and `TIMESTAMP WITH LOCAL TIME ZONE`. Their values are normalized to UTC.
+const UTC: &str = "+00:00";
+
+/// The maximum length of varchar2 and raw bind values. Longer values are
+/// bound as CLOB and BLOB respectively.
+const MAX_VARCHAR2_LEN: usize = 4000;
+const MAX_RAW_LEN: usize = 2000;
+
+fn arrow_error(err: ArrowError) -> Error {
+    Error::InternalError(format!("arrow error: {}", err))
+}
+
+fn time_unit(fsprec: u8) -> TimeUnit {
+    match fsprec {
+        0 => TimeUnit::Second,
+        1..=3 => TimeUnit::Millisecond,
+        4..=6 => TimeUnit::Microsecond,
+        _ => TimeUnit::Nanosecond,
+    }
+}
+
+/// Returns the arrow data type corresponding to the Oracle type.
+fn data_type(oratype: &OracleType) -> DataType {
+    match *oratype {
+        OracleType::Number(_, -127) | OracleType::Float(_) | OracleType::BinaryDouble => {
+            DataType::Float64
+        }
+        OracleType::Number(prec, 0) if 0 < prec && prec < DPI_MAX_INT64_PRECISION as u8 => {
+            DataType::Int64
+        }
+        OracleType::Number(prec, scale) => {
+            let prec = if prec == 0 { 38 } else { prec };
+            DataType::Decimal128(cmp::max(prec as i16, scale as i16) as u8, scale)
+        }
+        OracleType::BinaryFloat => DataType::Float32,
+        OracleType::Int64 => DataType::Int64,
+        OracleType::UInt64 => DataType::UInt64,
+        OracleType::Date => DataType::Timestamp(TimeUnit::Second, None),
+        OracleType::Timestamp(fsprec) => DataType::Timestamp(time_unit(fsprec), None),
+        OracleType::TimestampTZ(fsprec) | OracleType::TimestampLTZ(fsprec) => {
+            DataType::Timestamp(time_unit(fsprec), Some(UTC.into()))
+        }
+        OracleType::Raw(_) | OracleType::LongRaw | OracleType::BLOB => DataType::Binary,
+        OracleType::Boolean => DataType::Boolean,
+        // Other types are converted to their text representation.
+        _ => DataType::Utf8,
+    }
+}
+
+/// Returns the number of days since 1970-01-01 in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let yoe = year - era * 400;
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// Returns (year, month, day) from the number of days since 1970-01-01.
+/// This is the inverse of `days_from_civil`.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719468;
+    let era = if days >= 0 { days } else { days - 146096 } / 146097;
+    let doe = days - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month as u32, day as u32)
+}
+
+fn timestamp_to_epoch(ts: &Timestamp, unit: &TimeUnit, utc: bool) -> Result<i64> {
+    let days = days_from_civil(ts.year() as i64, ts.month() as i64, ts.day() as i64);
+    let mut secs =
+        days * 86400 + ts.hour() as i64 * 3600 + ts.minute() as i64 * 60 + ts.second() as i64;
+    if utc {
+        secs -= ts.tz_offset() as i64;
+    }
+    let nsecs = ts.nanosecond() as i64;
+    let (mult, div) = match *unit {
+        TimeUnit::Second => (1, 1_000_000_000),
+        TimeUnit::Millisecond => (1_000, 1_000_000),
+        TimeUnit::Microsecond => (1_000_000, 1_000),
+        TimeUnit::Nanosecond => (1_000_000_000, 1),
+    };
+    secs.checked_mul(mult)
+        .and_then(|val| val.checked_add(nsecs / div))
+        .ok_or_else(|| Error::OutOfRange(format!("timestamp overflow: {}", ts)))
+}
+
+fn epoch_to_timestamp(val: i64, unit: &TimeUnit) -> Timestamp {
+    let units_per_sec = match *unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => 1_000,
+        TimeUnit::Microsecond => 1_000_000,
+        TimeUnit::Nanosecond => 1_000_000_000,
+    };
+    let (secs, frac) = floor_div_rem(val, units_per_sec);
+    let (days, secs) = floor_div_rem(secs, 86400);
+    let (year, month, day) = civil_from_days(days);
+    Timestamp::new(
+        year as i32,
+        month,
+        day,
+        (secs / 3600) as u32,
+        (secs % 3600 / 60) as u32,
+        (secs % 60) as u32,
+        (frac * (1_000_000_000 / units_per_sec)) as u32,
+    )
+}
+
+fn floor_div_rem(val: i64, div: i64) -> (i64, i64) {
+    let (quot, rem) = (val / div, val % div);
+    if rem < 0 {
+        (quot - 1, rem + div)
+    } else {
+        (quot, rem)
+    }
+}
+
+fn number_to_decimal128(num: &OracleNumber, scale: i8) -> Result<i128> {
+    let err = || {
+        Error::OutOfRange(format!(
+            "{} doesn't fit in decimal128 with scale {}",
+            num, scale
+        ))
+    };
+    let factor: OracleNumber = format!("1e{}", scale).parse()?;
+    let scaled = num.checked_mul(&factor).ok_or_else(err)?;
+    scaled.to_string().parse().map_err(|_| err())
+}
+
+/// Column values in a record batch
+enum ColumnData {
+    Boolean(Vec<Option<bool>>),
+    Int64(Vec<Option<i64>>),
+    UInt64(Vec<Option<u64>>),
+    Float32(Vec<Option<f32>>),
+    Float64(Vec<Option<f64>>),
+    Decimal128(Vec<Option<i128>>),
+    Timestamp(Vec<Option<i64>>),
+    Utf8(Vec<Option<String>>),
+    Binary(Vec<Option<Vec<u8>>>),
+}
+
+impl ColumnData {
+    fn new(data_type: &DataType, capacity: usize) -> ColumnData {
+        match *data_type {
+            DataType::Boolean => ColumnData::Boolean(Vec::with_capacity(capacity)),
+            DataType::Int64 => ColumnData::Int64(Vec::with_capacity(capacity)),
+            DataType::UInt64 => ColumnData::UInt64(Vec::with_capacity(capacity)),
+            DataType::Float32 => ColumnData::Float32(Vec::with_capacity(capacity)),
+            DataType::Float64 => ColumnData::Float64(Vec::with_capacity(capacity)),
+            DataType::Decimal128(_, _) => ColumnData::Decimal128(Vec::with_capacity(capacity)),
+            DataType::Timestamp(_, _) => ColumnData::Timestamp(Vec::with_capacity(capacity)),
+            DataType::Binary => ColumnData::Binary(Vec::with_capacity(capacity)),
+            _ => ColumnData::Utf8(Vec::with_capacity(capacity)),
+        }
+    }
+
+    fn push(&mut self, val: &SqlValue, data_type: &DataType) -> Result<()> {
+        if val.is_null()? {
+            match *self {
+                ColumnData::Boolean(ref mut vec) => vec.push(None),
+                ColumnData::Int64(ref mut vec) => vec.push(None),
+                ColumnData::UInt64(ref mut vec) => vec.push(None),
+                ColumnData::Float32(ref mut vec) => vec.push(None),
+                ColumnData::Float64(ref mut vec) => vec.push(None),
+                ColumnData::Decimal128(ref mut vec) => vec.push(None),
+                ColumnData::Timestamp(ref mut vec) => vec.push(None),
+                ColumnData::Utf8(ref mut vec) => vec.push(None),
+                ColumnData::Binary(ref mut vec) => vec.push(None),
+            }
+            return Ok(());
+        }
+        match *self {
+            ColumnData::Boolean(ref mut vec) => vec.push(Some(val.get()?)),
+            ColumnData::Int64(ref mut vec) => vec.push(Some(val.get()?)),
+            ColumnData::UInt64(ref mut vec) => vec.push(Some(val.get()?)),
+            ColumnData::Float32(ref mut vec) => vec.push(Some(val.get()?)),
+            ColumnData::Float64(ref mut vec) => vec.push(Some(val.get()?)),
+            ColumnData::Decimal128(ref mut vec) => {
+                let scale = match *data_type {
+                    DataType::Decimal128(_, scale) => scale,
+                    _ => 0,
+                };
+                vec.push(Some(number_to_decimal128(&val.get()?, scale)?))
+            }
+            ColumnData::Timestamp(ref mut vec) => {
+                let (unit, utc) = match *data_type {
+                    DataType::Timestamp(ref unit, ref tz) => (unit.clone(), tz.is_some()),
+                    _ => (TimeUnit::Nanosecond, false),
+                };
+                vec.push(Some(timestamp_to_epoch(&val.get()?, &unit, utc)?))
+            }
+            ColumnData::Utf8(ref mut vec) => vec.push(Some(val.get()?)),
+            ColumnData::Binary(ref mut vec) => vec.push(Some(val.get()?)),
+        }
+        Ok(())
+    }
+
+    fn into_array(self, data_type: &DataType) -> Result<ArrayRef> {
+        Ok(match self {
+            ColumnData::Boolean(vec) => Arc::new(BooleanArray::from(vec)),
+            ColumnData::Int64(vec) => Arc::new(Int64Array::from(vec)),
+            ColumnData::UInt64(vec) => Arc::new(UInt64Array::from(vec)),
+            ColumnData::Float32(vec) => Arc::new(Float32Array::from(vec)),
+            ColumnData::Float64(vec) => Arc::new(Float64Array::from(vec)),
+            ColumnData::Decimal128(vec) => {
+                let (prec, scale) = match *data_type {
+                    DataType::Decimal128(prec, scale) => (prec, scale),
+                    _ => (38, 0),
+                };
+                let array = Decimal128Array::from(vec)
+                    .with_precision_and_scale(prec, scale)
+                    .map_err(arrow_error)?;
+                Arc::new(array)
+            }
+            ColumnData::Timestamp(vec) => {
+                let (unit, tz) = match *data_type {
+                    DataType::Timestamp(ref unit, ref tz) => (unit.clone(), tz.clone()),
+                    _ => (TimeUnit::Nanosecond, None),
+                };
+                match unit {
+                    TimeUnit::Second => {
+                        Arc::new(TimestampSecondArray::from(vec).with_timezone_opt(tz))
+                    }
+                    TimeUnit::Millisecond => {
+                        Arc::new(TimestampMillisecondArray::from(vec).with_timezone_opt(tz))
+                    }
+                    TimeUnit::Microsecond => {
+                        Arc::new(TimestampMicrosecondArray::from(vec).with_timezone_opt(tz))
+                    }
+                    TimeUnit::Nanosecond => {
+                        Arc::new(TimestampNanosecondArray::from(vec).with_timezone_opt(tz))
+                    }
+                }
+            }
+            ColumnData::Utf8(vec) => Arc::new(vec.into_iter().collect::<StringArray>()),
+            ColumnData::Binary(vec) => Arc::new(vec.into_iter().collect::<BinaryArray>()),
+        })
+    }
+}
+
+/// Iterator over [RecordBatch][]es converted from rows in a result set
+///
+/// This is created by [ResultSet.into_record_batches][] and is available
+/// when `arrow` feature is enabled.
+///
+/// [RecordBatch]: https://docs.rs/arrow/54/arrow/record_batch/struct.RecordBatch.html
+/// [ResultSet.into_record_batches]: struct.ResultSet.html#method.into_record_batches
+pub struct RecordBatches<'a, T>
+where
+    T: RowValue,
+{
+    rs: ResultSet<'a, T>,
+    schema: SchemaRef,
+    batch_size: usize,
+    done: bool,
+}
+
+impl<'a, T> RecordBatches<'a, T>
+where
+    T: RowValue,
+{
+    /// Returns the arrow schema of record batches.
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn next_batch(&mut self) -> Result<Option<RecordBatch>> {
+        let fields = self.schema.fields();
+        let mut columns = fields
+            .iter()
+            .map(|field| ColumnData::new(field.data_type(), self.batch_size))
+            .collect::<Vec<_>>();
+        let mut num_rows = 0;
+        while num_rows < self.batch_size {
+            match self.rs.stmt().next() {
+                Some(row) => {
+                    let row = row?;
+                    for (idx, val) in row.sql_values().iter().enumerate() {
+                        columns[idx].push(val, fields[idx].data_type())?;
+                    }
+                    num_rows += 1;
+                }
+                None => {
+                    self.done = true;
+                    break;
+                }
+            }
+        }
+        if num_rows == 0 {
+            return Ok(None);
+        }
+        let mut arrays = Vec::with_capacity(columns.len());
+        for (idx, column) in columns.into_iter().enumerate() {
+            arrays.push(column.into_array(fields[idx].data_type())?);
+        }
+        let batch = RecordBatch::try_new(self.schema.clone(), arrays).map_err(arrow_error)?;
+        Ok(Some(batch))
+    }
+}
+
+impl<'a, T> Iterator for RecordBatches<'a, T>
+where
+    T: RowValue,
+{
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.next_batch() {
+            Ok(Some(batch)) => Some(Ok(batch)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+impl<'a, T> ResultSet<'a, T>
+where
+    T: RowValue,
+{
+    /// Converts the remaining rows into arrow [RecordBatch][]es containing
+    /// at most `batch_size` rows each.
+    ///
+    /// This is available when `arrow` feature is enabled.
+    /// The arrow schema is built from [ColumnInfo.oracle_type()][]:
+    ///
+    /// | Oracle Type | Arrow Data Type |
+    /// | --- | --- |
+    /// | number(p) where p < 18 | Int64 |
+    /// | number(p, s) | Decimal128(p, s) |
+    /// | number, float, binary_double | Float64 |
+    /// | binary_float | Float32 |
+    /// | date | Timestamp(Second) |
+    /// | timestamp(n) | Timestamp(unit for n fractional digits) |
+    /// | timestamp(n) with (local) time zone | Timestamp(unit for n fractional digits, "+00:00") |
+    /// | raw, long raw, BLOB | Binary |
+    /// | boolean | Boolean |
+    /// | others | Utf8 |
+    ///
+    /// Null values in columns are set in the null bitmaps.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::*; fn try_main() -> Result<()> {
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    /// let mut stmt = conn.prepare("select * from emp", &[])?;
+    /// for batch in stmt.query(&[])?.into_record_batches(1000)? {
+    ///     let batch = batch?;
+    ///     println!("{} rows", batch.num_rows());
+    /// }
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    ///
+    /// [RecordBatch]: https://docs.rs/arrow/54/arrow/record_batch/struct.RecordBatch.html
+    /// [ColumnInfo.oracle_type()]: struct.ColumnInfo.html#method.oracle_type
+    pub fn into_record_batches(self, batch_size: usize) -> Result<RecordBatches<'a, T>> {
+        if batch_size == 0 {
+            return Err(Error::OutOfRange(
+                "batch_size must be greater than zero".to_string(),
+            ));
+        }
+        let fields = self
+            .column_info()
+            .iter()
+            .map(|info| Field::new(info.name(), data_type(info.oracle_type()), info.nullable()))
+            .collect::<Vec<_>>();
+        Ok(RecordBatches {
+            rs: self,
+            schema: Arc::new(Schema::new(fields)),
+            batch_size,
+            done: false,
+        })
+    }
+}
+
+/// Returns the Oracle type used to bind values in the arrow array.
+fn bind_type(array: &ArrayRef) -> Result<OracleType> {
+    Ok(match *array.data_type() {
+        DataType::Boolean
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32 => OracleType::Int64,
+        DataType::UInt64 => OracleType::UInt64,
+        DataType::Float32 => OracleType::BinaryFloat,
+        DataType::Float64 => OracleType::BinaryDouble,
+        DataType::Decimal128(prec, scale) => OracleType::Number(prec, scale),
+        DataType::Utf8 => {
+            let len = array
+                .as_string::<i32>()
+                .iter()
+                .flatten()
+                .map(str::len)
+                .max()
+                .unwrap_or(0);
+            if len > MAX_VARCHAR2_LEN {
+                OracleType::CLOB
+            } else {
+                OracleType::Varchar2(cmp::max(len, 1) as u32)
+            }
+        }
+        DataType::Binary => {
+            let len = array
+                .as_binary::<i32>()
+                .iter()
+                .flatten()
+                .map(<[u8]>::len)
+                .max()
+                .unwrap_or(0);
+            if len > MAX_RAW_LEN {
+                OracleType::BLOB
+            } else {
+                OracleType::Raw(cmp::max(len, 1) as u32)
+            }
+        }
+        DataType::Date32 | DataType::Date64 => OracleType::Date,
+        DataType::Timestamp(_, None) => OracleType::Timestamp(9),
+        DataType::Timestamp(_, Some(_)) => OracleType::TimestampTZ(9),
+        ref data_type => {
+            return Err(Error::InvalidTypeConversion(
+                format!("{:?}", data_type),
+                "Oracle type".to_string(),
+            ))
+        }
+    })
+}
+
+fn timestamp_value(array: &ArrayRef, unit: &TimeUnit, row: usize) -> i64 {
+    match *unit {
+        TimeUnit::Second => array.as_primitive::<TimestampSecondType>().value(row),
+        TimeUnit::Millisecond => array.as_primitive::<TimestampMillisecondType>().value(row),
+        TimeUnit::Microsecond => array.as_primitive::<TimestampMicrosecondType>().value(row),
+        TimeUnit::Nanosecond => array.as_primitive::<TimestampNanosecondType>().value(row),
+    }
+}
+
+/// Sets the value at `row` in the arrow array to the bind value.
+fn set_bind_value(val: &mut SqlValue, array: &ArrayRef, row: usize) -> Result<()> {
+    if array.is_null(row) {
+        return val.set_null();
+    }
+    match *array.data_type() {
+        DataType::Boolean => val.set(&(array.as_boolean().value(row) as i64)),
+        DataType::Int8 => val.set(&(array.as_primitive::<Int8Type>().value(row) as i64)),
+        DataType::Int16 => val.set(&(array.as_primitive::<Int16Type>().value(row) as i64)),
+        DataType::Int32 => val.set(&(array.as_primitive::<Int32Type>().value(row) as i64)),
+        DataType::Int64 => val.set(&array.as_primitive::<Int64Type>().value(row)),
+        DataType::UInt8 => val.set(&(array.as_primitive::<UInt8Type>().value(row) as i64)),
+        DataType::UInt16 => val.set(&(array.as_primitive::<UInt16Type>().value(row) as i64)),
+        DataType::UInt32 => val.set(&(array.as_primitive::<UInt32Type>().value(row) as i64)),
+        DataType::UInt64 => val.set(&array.as_primitive::<UInt64Type>().value(row)),
+        DataType::Float32 => val.set(&array.as_primitive::<Float32Type>().value(row)),
+        DataType::Float64 => val.set(&array.as_primitive::<Float64Type>().value(row)),
+        DataType::Decimal128(_, _) => {
+            let num: OracleNumber = array
+                .as_primitive::<Decimal128Type>()
+                .value_as_string(row)
+                .parse()?;
+            val.set(&num)
+        }
+        DataType::Utf8 => val.set(&array.as_string::<i32>().value(row)),
+        DataType::Binary => val.set(&array.as_binary::<i32>().value(row)),
+        DataType::Date32 => {
+            let days = array.as_primitive::<Date32Type>().value(row) as i64;
+            val.set(&epoch_to_timestamp(days * 86400, &TimeUnit::Second))
+        }
+        DataType::Date64 => {
+            let msecs = array.as_primitive::<Date64Type>().value(row);
+            val.set(&epoch_to_timestamp(msecs, &TimeUnit::Millisecond))
+        }
+        DataType::Timestamp(ref unit, ref tz) => {
+            let ts = epoch_to_timestamp(timestamp_value(array, unit, row), unit);
+            if tz.is_some() {
+                val.set(&ts.and_tz_offset(0))
+            } else {
+                val.set(&ts)
+            }
+        }
+        ref data_type => Err(Error::InvalidTypeConversion(
+            format!("{:?}", data_type),
+            "Oracle type".to_string(),
+        )),
+    }
+}
+
+/// Result of [Connection.insert_record_batch][]
+///
+/// This is available when `arrow` feature is enabled.
+///
+/// [Connection.insert_record_batch]: struct.Connection.html#method.insert_record_batch
+#[derive(Debug, Clone)]
+pub struct BatchInsertResult {
+    rows_inserted: u64,
+    batch_errors: Vec<DbError>,
+}
+
+impl BatchInsertResult {
+    /// Returns the number of inserted rows.
+    pub fn rows_inserted(&self) -> u64 {
+        self.rows_inserted
+    }
+
+    /// Returns errors of rows failed to be inserted.
+    ///
+    /// [DbError.offset()][] is the zero-based index of the failed row in the record batch.
+    ///
+    /// [DbError.offset()]: struct.DbError.html#method.offset
+    pub fn batch_errors(&self) -> &[DbError] {
+        &self.batch_errors
+    }
+}
+
+/// Returns `name` as it is when it is a nonquoted identifier. Otherwise,
+/// returns a quoted identifier.
+fn quote_identifier(name: &str) -> String {
+    let mut chars = name.chars();
+    let nonquoted = match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$' || c == '#')
+        }
+        _ => false,
+    };
+    if nonquoted {
+        name.to_string()
+    } else {
+        format!("\"{}\"", name.replace('"', "\"\""))
+    }
+}
+
+impl Connection {
+    /// Inserts rows in an arrow [RecordBatch][] into `table` in one network
+    /// round trip by array DML.
+    ///
+    /// This is available when `arrow` feature is enabled.
+    /// The field names in the arrow schema are used as column names.
+    /// `table` and column names are used as they are when they are nonquoted
+    /// identifiers such as `emp` and `scott.emp`. Otherwise, they are enclosed
+    /// in double quotes and are case-sensitive. Values are bound as follows:
+    ///
+    /// | Arrow Data Type | Oracle Type |
+    /// | --- | --- |
+    /// | Boolean (as 1 or 0), Int8, Int16, Int32, Int64, UInt8, UInt16, UInt32 | Int64 |
+    /// | UInt64 | UInt64 |
+    /// | Float32 | binary_float |
+    /// | Float64 | binary_double |
+    /// | Decimal128(p, s) | number(p, s) |
+    /// | Utf8 | varchar2, or CLOB when a value is longer than 4000 bytes |
+    /// | Binary | raw, or BLOB when a value is longer than 2000 bytes |
+    /// | Date32, Date64 | date |
+    /// | Timestamp(unit, None) | timestamp(9) |
+    /// | Timestamp(unit, Some(tz)) | timestamp(9) with time zone in UTC |
+    ///
+    /// Other arrow data types cause `Error::InvalidTypeConversion`.
+    ///
+    /// Rows failing to be inserted don't stop the insertion of other rows.
+    /// Their errors are returned by [BatchInsertResult.batch_errors()][].
+    /// The inserted rows are committed when [autocommit][] is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::*; fn try_main() -> Result<()> {
+    /// # let batch: arrow::record_batch::RecordBatch = unimplemented!();
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    /// let result = conn.insert_record_batch("emp", &batch)?;
+    /// println!("{} rows inserted", result.rows_inserted());
+    /// for err in result.batch_errors() {
+    ///     println!("row {}: {}", err.offset(), err.message());
+    /// }
+    /// conn.commit()?;
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    ///
+    /// [RecordBatch]: https://docs.rs/arrow/54/arrow/record_batch/struct.RecordBatch.html
+    /// [BatchInsertResult.batch_errors()]: struct.BatchInsertResult.html#method.batch_errors
+    /// [autocommit]: struct.Connection.html#method.set_autocommit
+    pub fn insert_record_batch(
+        &self,
+        table: &str,
+        batch: &RecordBatch,
+    ) -> Result<BatchInsertResult> {
+        let num_rows = batch.num_rows();
+        if num_rows > u32::max_value() as usize {
+            return Err(Error::OutOfRange(format!(
+                "too many rows in a record batch: {}",
+                num_rows
+            )));
+        }
+        let columns = batch.columns();
+        let oratypes = columns.iter().map(bind_type).collect::<Result<Vec<_>>>()?;
+        if num_rows == 0 || columns.is_empty() {
+            return Ok(BatchInsertResult {
+                rows_inserted: 0,
+                batch_errors: Vec::new(),
+            });
+        }
+        let schema = batch.schema();
+        let column_names = schema
+            .fields()
+            .iter()
+            .map(|field| quote_identifier(field.name()))
+            .collect::<Vec<_>>();
+        let bind_names = (1..=columns.len())
+            .map(|idx| format!(":{}", idx))
+            .collect::<Vec<_>>();
+        let sql = format!(
+            "insert into {} ({}) values ({})",
+            table
+                .split('.')
+                .map(quote_identifier)
+                .collect::<Vec<_>>()
+                .join("."),
+            column_names.join(", "),
+            bind_names.join(", ")
+        );
+        let mut stmt = self.prepare(&sql, &[])?;
+        let (rows_inserted, batch_errors) =
+            stmt.execute_many_internal(&oratypes, num_rows as u32, |val, col, row| {
+                set_bind_value(val, &columns[col], row)
+            })?;
+        Ok(BatchInsertResult {
+            rows_inserted,
+            batch_errors,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote_identifier() {
+        assert_eq!(quote_identifier("emp"), "emp");
+        assert_eq!(quote_identifier("Int_Col$#1"), "Int_Col$#1");
+        assert_eq!(quote_identifier("1col"), "\"1col\"");
+        assert_eq!(quote_identifier("int col"), "\"int col\"");
+        assert_eq!(
+            quote_identifier("x) values (1); --"),
+            "\"x) values (1); --\""
+        );
+        assert_eq!(quote_identifier("a\"b"), "\"a\"\"b\"");
+        assert_eq!(quote_identifier(""), "\"\"");
+    }
+
+    #[test]
+    fn test_days_from_civil() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        assert_eq!(days_from_civil(2000, 3, 1), 11017);
+        assert_eq!(days_from_civil(2012, 3, 4), 15403);
+    }
+
+    #[test]
+    fn test_civil_from_days() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(11017), (2000, 3, 1));
+        assert_eq!(civil_from_days(15403), (2012, 3, 4));
+        for days in -800000..800000 {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month as i64, day as i64), days);
+        }
+    }
+
+    #[test]
+    fn test_epoch_to_timestamp() {
+        let ts = epoch_to_timestamp(-1, &TimeUnit::Millisecond);
+        assert_eq!(ts, Timestamp::new(1969, 12, 31, 23, 59, 59, 999_000_000));
+        let ts = epoch_to_timestamp(1330819506_123456, &TimeUnit::Microsecond);
+        assert_eq!(ts, Timestamp::new(2012, 3, 4, 0, 5, 6, 123_456_000));
+    }
+
+    #[test]
+    fn test_number_to_decimal128() {
+        let num: OracleNumber = "123.45".parse().unwrap();
+        assert_eq!(number_to_decimal128(&num, 2).unwrap(), 12345);
+        assert_eq!(number_to_decimal128(&num, 4).unwrap(), 1234500);
+        let num: OracleNumber = "-12300".parse().unwrap();
+        assert_eq!(number_to_decimal128(&num, -2).unwrap(), -123);
+        let num: OracleNumber = "0.125".parse().unwrap();
+        assert!(number_to_decimal128(&num, 2).is_err());
+    }
+}
@@ -4843,3 +4843,16 @@ extern "C" {
         status: *mut u32,
     ) -> ::std::os::raw::c_int;
 }
+extern "C" {
+    pub fn dpi_ext_dpiConn_findStmtInCache(
+        conn: *mut dpiConn,
+        sql: *const ::std::os::raw::c_char,
+        sqlLength: u32,
+        tag: *const ::std::os::raw::c_char,
+        tagLength: u32,
+        found: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn dpi_ext_dpiStmt_deleteFromCache(stmt: *mut dpiStmt) -> ::std::os::raw::c_int;
+}
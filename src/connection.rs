@@ -26,21 +26,27 @@ use crate::error::error_from_dpi_error;
 use crate::new_odpi_str;
 use crate::sql_type::ObjectType;
 use crate::sql_type::ObjectTypeInternal;
+use crate::sql_type::OracleType;
 use crate::sql_type::ToSql;
+use crate::statement::OutputTypeHandler;
 use crate::to_odpi_str;
 use crate::to_rust_slice;
 use crate::to_rust_str;
 use crate::AssertSend;
 use crate::AssertSync;
+use crate::ColumnInfo;
 use crate::Context;
 use crate::DpiConn;
 use crate::DpiObjectType;
 use crate::Error;
+use crate::ParseSqlError;
 use crate::Result;
 use crate::ResultSet;
 use crate::Row;
 use crate::RowValue;
 use crate::Statement;
+use crate::StatementBuilder;
+#[allow(deprecated)]
 use crate::StmtParam;
 use crate::Version;
 
@@ -450,6 +456,58 @@ impl Connector {
     }
 }
 
+/// A handle to cancel the running call of a connection from other threads
+///
+/// This is created by [Connection.cancel_handle][]. It holds a reference to
+/// the underlying connection handle and can be cloned and sent to other
+/// threads cheaply.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use oracle::*; use std::thread; use std::time::Duration;
+/// # fn try_main() -> Result<()> {
+/// let conn = Connection::connect("scott", "tiger", "")?;
+/// let cancel_handle = conn.cancel_handle();
+/// thread::spawn(move || {
+///     thread::sleep(Duration::from_secs(10));
+///     cancel_handle.cancel().unwrap();
+/// });
+/// match conn.execute("begin long_running_procedure; end;", &[]) {
+///     Err(Error::Cancelled(_)) => println!("cancelled"),
+///     result => {
+///         result?;
+///     }
+/// }
+/// # Ok(())} fn main() { try_main().unwrap(); }
+/// ```
+///
+/// [Connection.cancel_handle]: struct.Connection.html#method.cancel_handle
+#[derive(Clone)]
+pub struct CancelHandle {
+    ctxt: &'static Context,
+    handle: DpiConn,
+}
+
+impl AssertSync for CancelHandle {}
+impl AssertSend for CancelHandle {}
+
+impl CancelHandle {
+    /// Interrupts the running call of the connection.
+    ///
+    /// The interrupted call returns `Error::Cancelled`.
+    pub fn cancel(&self) -> Result<()> {
+        chkerr!(self.ctxt, dpiConn_breakExecution(self.handle.raw()));
+        Ok(())
+    }
+}
+
+impl fmt::Debug for CancelHandle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CancelHandle {{ handle: {:?} }}", self.handle.raw())
+    }
+}
+
 /// Connection to an Oracle database
 pub struct Connection {
     pub(crate) ctxt: &'static Context,
@@ -458,6 +516,8 @@ pub struct Connection {
     tag_found: bool,
     pub(crate) autocommit: bool,
     pub(crate) objtype_cache: Mutex<HashMap<String, Arc<ObjectTypeInternal>>>,
+    pub(crate) output_type_handler: Option<OutputTypeHandler>,
+    pub(crate) max_lob_size: Option<u64>,
 }
 
 impl AssertSync for Context {}
@@ -539,6 +599,8 @@ impl Connection {
             tag_found: conn_params.outTagFound != 0,
             autocommit: false,
             objtype_cache: Mutex::new(HashMap::new()),
+            output_type_handler: None,
+            max_lob_size: None,
         })
     }
 
@@ -586,24 +648,66 @@ impl Connection {
     ///
     /// Query methods in Connection allocate memory for 100 rows by default
     /// to reduce the number of network round trips in case that many rows are
-    /// fetched. When 100 isn't preferable, use `StmtParam::FetchArraySize(u32)`
+    /// fetched. When 100 isn't preferable, use [Connection.statement][]
     /// to customize it.
     ///
+    /// [Connection.statement]: #method.statement
+    #[allow(deprecated)]
+    pub fn prepare(&self, sql: &str, params: &[StmtParam]) -> Result<Statement> {
+        Statement::new(self, sql, params)
+    }
+
+    /// Parses the SQL text on the server without executing it.
+    ///
+    /// It returns `Ok(None)` when the SQL text is valid. When the server
+    /// reports an error, it returns the error with the position in the
+    /// SQL text as line and column numbers. DDL statements are rejected
+    /// by `Error::InvalidOperation` because they may be executed on parse.
+    ///
+    /// # Examples
+    ///
     /// ```no_run
     /// # use oracle::*; fn try_main() -> Result<()> {
-    /// # let conn = Connection::connect("scott", "tiger", "")?;
-    /// // fetch top 10 rows.
-    /// let mut stmt = conn.prepare("select * from (select empno, ename from emp order by empno) where rownum <= 10",
-    ///                             &[StmtParam::FetchArraySize(10)])?;
-    /// for row_result in stmt.query_as::<(i32, String)>(&[])? {
-    ///     let (empno, ename) = row_result?;
-    ///     println!("empno: {}, ename: {}", empno, ename);
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    /// let sql = "select ename\n  from empp";
+    /// if let Some(err) = conn.parse(sql)? {
+    ///     // ORA-00942: table or view does not exist at line 2, column 8
+    ///     println!("{}", err);
     /// }
     /// # Ok(())} fn main() { try_main().unwrap(); }
     /// ```
+    pub fn parse(&self, sql: &str) -> Result<Option<ParseSqlError>> {
+        let mut stmt = self.statement(sql).build()?;
+        match stmt.parse() {
+            Ok(()) => Ok(None),
+            Err(Error::OciError(err)) => Ok(Some(ParseSqlError::new(sql, err))),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Creates [StatementBuilder][] to prepare a statement with parameters
+    /// such as fetch array size, statement cache tag, per-statement
+    /// autocommit mode and call timeout.
     ///
-    pub fn prepare(&self, sql: &str, params: &[StmtParam]) -> Result<Statement> {
-        Statement::new(self, sql, params)
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::*; use std::time::Duration; fn try_main() -> Result<()> {
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    /// // Commit this statement even when autocommit mode is off and
+    /// // give up when it takes more than 10 seconds.
+    /// let mut stmt = conn
+    ///     .statement("update emp set sal = sal * 1.1 where deptno = :1")
+    ///     .commit_on_success(true)
+    ///     .call_timeout(Duration::from_secs(10))
+    ///     .build()?;
+    /// stmt.execute(&[&10])?;
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    ///
+    /// [StatementBuilder]: struct.StatementBuilder.html
+    pub fn statement<'sql>(&self, sql: &'sql str) -> StatementBuilder<'_, 'sql> {
+        StatementBuilder::new(self, sql)
     }
 
     /// Executes a select statement and returns a result set containing [Row][]s.
@@ -682,7 +786,7 @@ impl Connection {
     ///
     /// [Query Methods]: https://github.com/kubo/rust-oracle/blob/master/docs/query-methods.md
     pub fn query_row(&self, sql: &str, params: &[&dyn ToSql]) -> Result<Row> {
-        let mut stmt = self.prepare(sql, &[StmtParam::FetchArraySize(1)])?;
+        let mut stmt = self.statement(sql).fetch_array_size(1).build()?;
         if let Err(err) = stmt.query_row(params) {
             return Err(err);
         };
@@ -695,7 +799,7 @@ impl Connection {
     ///
     /// [Query Methods]: https://github.com/kubo/rust-oracle/blob/master/docs/query-methods.md
     pub fn query_row_named(&self, sql: &str, params: &[(&str, &dyn ToSql)]) -> Result<Row> {
-        let mut stmt = self.prepare(sql, &[StmtParam::FetchArraySize(1)])?;
+        let mut stmt = self.statement(sql).fetch_array_size(1).build()?;
         if let Err(err) = stmt.query_row_named(params) {
             return Err(err);
         };
@@ -711,7 +815,7 @@ impl Connection {
     where
         T: RowValue,
     {
-        let mut stmt = self.prepare(sql, &[StmtParam::FetchArraySize(1)])?;
+        let mut stmt = self.statement(sql).fetch_array_size(1).build()?;
         stmt.query_row_as::<T>(params)
     }
 
@@ -724,7 +828,7 @@ impl Connection {
     where
         T: RowValue,
     {
-        let mut stmt = self.prepare(sql, &[StmtParam::FetchArraySize(1)])?;
+        let mut stmt = self.statement(sql).fetch_array_size(1).build()?;
         stmt.query_row_as_named::<T>(params)
     }
 
@@ -799,12 +903,93 @@ impl Connection {
         self.autocommit = autocommit;
     }
 
+    /// Sets a callback to determine the data type to define each column
+    /// of queries in the connection.
+    ///
+    /// The callback is called with column information when a query
+    /// is executed first. When it returns `Ok(Some(oratype))`, the column
+    /// is fetched as `oratype`. When it returns `Ok(None)`, the column is
+    /// fetched as usual. It is overridden by
+    /// [StatementBuilder.output_type_handler][].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::*; use oracle::sql_type::OracleType; fn try_main() -> Result<()> {
+    /// let mut conn = Connection::connect("scott", "tiger", "")?;
+    /// // Fetch DATE columns as TIMESTAMP and CLOB columns as LONG
+    /// // to reduce round trips per row.
+    /// conn.set_output_type_handler(|info| match *info.oracle_type() {
+    ///     OracleType::Date => Ok(Some(OracleType::Timestamp(0))),
+    ///     OracleType::CLOB => Ok(Some(OracleType::Long)),
+    ///     _ => Ok(None),
+    /// });
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    ///
+    /// [StatementBuilder.output_type_handler]: struct.StatementBuilder.html#method.output_type_handler
+    pub fn set_output_type_handler<F>(&mut self, handler: F)
+    where
+        F: Fn(&ColumnInfo) -> Result<Option<OracleType>> + Send + Sync + 'static,
+    {
+        self.output_type_handler = Some(OutputTypeHandler::new(handler));
+    }
+
+    /// Removes the callback set by [Connection.set_output_type_handler][].
+    ///
+    /// [Connection.set_output_type_handler]: #method.set_output_type_handler
+    pub fn clear_output_type_handler(&mut self) {
+        self.output_type_handler = None;
+    }
+
+    /// Gets the maximum size in bytes of LOB values converted to `String`
+    /// or `Vec<u8>` in queries of the connection.
+    /// It is `None`, which means no limit, by default.
+    pub fn max_lob_size(&self) -> Option<u64> {
+        self.max_lob_size
+    }
+
+    /// Sets the maximum size in bytes of LOB values converted to `String`
+    /// or `Vec<u8>` in queries of the connection.
+    ///
+    /// When a `CLOB`, `NCLOB` or `BLOB` column value is larger than the size,
+    /// the conversion returns `Error::OutOfRange` naming the column instead of
+    /// reading the whole value into memory. The size also limits values
+    /// fetched by [StatementBuilder.fetch_lobs_inline][]. `None` removes the limit.
+    ///
+    /// The size is applied to queries executed first after it is set.
+    /// It is overridden by [StatementBuilder.max_lob_size][].
+    ///
+    /// [StatementBuilder.max_lob_size]: struct.StatementBuilder.html#method.max_lob_size
+    /// [StatementBuilder.fetch_lobs_inline]: struct.StatementBuilder.html#method.fetch_lobs_inline
+    pub fn set_max_lob_size(&mut self, size: Option<u64>) {
+        self.max_lob_size = size;
+    }
+
     /// Cancels execution of running statements in the connection
+    ///
+    /// The cancelled call returns `Error::Cancelled`. Use [cancel_handle][]
+    /// to cancel it from a thread which doesn't have the connection.
+    ///
+    /// [cancel_handle]: #method.cancel_handle
     pub fn break_execution(&self) -> Result<()> {
         chkerr!(self.ctxt, dpiConn_breakExecution(self.handle.raw()));
         Ok(())
     }
 
+    /// Returns a handle to cancel the running call of the connection
+    /// from other threads.
+    ///
+    /// See [CancelHandle][].
+    ///
+    /// [CancelHandle]: struct.CancelHandle.html
+    pub fn cancel_handle(&self) -> CancelHandle {
+        CancelHandle {
+            ctxt: self.ctxt,
+            handle: self.handle.clone(),
+        }
+    }
+
     /// Gets an object type information from name
     ///
     /// ```no_run
@@ -35,6 +35,13 @@ pub enum Error {
     /// Error from an underlying ODPI-C layer.
     DpiError(DbError),
 
+    /// Error when the running call is cancelled by [CancelHandle.cancel][]
+    /// or [Connection.break_execution][]. (ORA-01013)
+    ///
+    /// [CancelHandle.cancel]: struct.CancelHandle.html#method.cancel
+    /// [Connection.break_execution]: struct.Connection.html#method.break_execution
+    Cancelled(DbError),
+
     /// Error when NULL value is got but the target rust type cannot handle NULL.
     /// Use `Option<...>` in this case.
     NullValue,
@@ -154,7 +161,8 @@ impl DbError {
         self.code
     }
 
-    /// ? (used for Batch Errors?)
+    /// byte offset of the error position in the SQL text when the error
+    /// occurs while parsing it, or row offset of batch errors
     pub fn offset(&self) -> u16 {
         self.offset
     }
@@ -175,11 +183,77 @@ impl DbError {
     }
 }
 
+/// An error found in SQL text by [Connection.parse][]
+///
+/// [Connection.parse]: struct.Connection.html#method.parse
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseSqlError {
+    db_error: DbError,
+    line: usize,
+    column: usize,
+}
+
+impl ParseSqlError {
+    pub(crate) fn new(sql: &str, db_error: DbError) -> ParseSqlError {
+        let mut offset = (db_error.offset() as usize).min(sql.len());
+        while !sql.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let before_error = &sql[..offset];
+        let line = before_error.matches('\n').count() + 1;
+        let line_start = before_error.rfind('\n').map(|pos| pos + 1).unwrap_or(0);
+        let column = before_error[line_start..].chars().count() + 1;
+        ParseSqlError {
+            db_error: db_error,
+            line: line,
+            column: column,
+        }
+    }
+
+    /// Oracle error returned by parsing the SQL text
+    pub fn db_error(&self) -> &DbError {
+        &self.db_error
+    }
+
+    /// one-based line number of the error position in the SQL text
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// one-based column number of the error position in the SQL text
+    ///
+    /// This is counted in characters, not in bytes.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl fmt::Display for ParseSqlError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} at line {}, column {}",
+            self.db_error.message, self.line, self.column
+        )
+    }
+}
+
+impl error::Error for ParseSqlError {
+    fn description(&self) -> &str {
+        "SQL parse error"
+    }
+
+    fn cause(&self) -> Option<&dyn error::Error> {
+        None
+    }
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             Error::OciError(ref err) => write!(f, "OCI Error: {}", err.message),
             Error::DpiError(ref err) => write!(f, "DPI Error: {}", err.message),
+            Error::Cancelled(ref err) => write!(f, "Cancelled: {}", err.message),
             Error::NullValue => write!(f, "NULL value found"),
             Error::ParseError(ref err) => write!(f, "{}", err),
             Error::OutOfRange(ref msg) => write!(f, "out of range: {}", msg),
@@ -208,6 +282,7 @@ impl fmt::Debug for Error {
         match *self {
             Error::OciError(ref err) => write!(f, "OciError({:?})", err),
             Error::DpiError(ref err) => write!(f, "DpiError({:?})", err),
+            Error::Cancelled(ref err) => write!(f, "Cancelled({:?})", err),
             Error::NullValue => write!(f, "NullValue"),
             Error::ParseError(ref err) => write!(f, "ParseError({:?})", err),
             Error::OutOfRange(ref msg) => write!(f, "OutOfRange({:?})", msg),
@@ -232,6 +307,7 @@ impl error::Error for Error {
         match *self {
             Error::OciError(_) => "Oracle OCI error",
             Error::DpiError(_) => "ODPI-C error",
+            Error::Cancelled(_) => "cancelled",
             Error::NullValue => "NULL value",
             Error::ParseError(_) => "parse error",
             Error::OutOfRange(_) => "out of range",
@@ -310,11 +386,25 @@ pub fn error_from_dpi_error(err: &dpiErrorInfo) -> Error {
     );
     if err.message().starts_with("DPI") {
         Error::DpiError(err)
+    } else if err.code() == 1013 {
+        // ORA-01013: user requested cancel of current operation
+        Error::Cancelled(err)
     } else {
         Error::OciError(err)
     }
 }
 
+/// Returns the error number of an ODPI-C error such as 1027 for `DPI-1027: ...`.
+///
+/// ODPI-C sets zero to the error code of its own errors. The number is
+/// available only in the message.
+pub(crate) fn dpi_error_code(err: &DbError) -> Option<i32> {
+    if !err.message().starts_with("DPI-") {
+        return None;
+    }
+    err.message()[4..].split(':').next()?.parse().ok()
+}
+
 pub(crate) fn error_from_context(ctxt: &Context) -> Error {
     let mut err: dpiErrorInfo = Default::default();
     unsafe {
@@ -343,3 +433,33 @@ macro_rules! chkerr {
         }
     }};
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_sql_error(sql: &str, offset: u16) -> ParseSqlError {
+        let err = DbError::new(
+            942,
+            offset,
+            "ORA-00942: table or view does not exist".into(),
+            "dpiStmt_execute".into(),
+            "execute".into(),
+        );
+        ParseSqlError::new(sql, err)
+    }
+
+    #[test]
+    fn parse_sql_error_position() {
+        let err = parse_sql_error("select * from no_such_table", 14);
+        assert_eq!((err.line(), err.column()), (1, 15));
+        let err = parse_sql_error("select *\n  from no_such_table", 16);
+        assert_eq!((err.line(), err.column()), (2, 8));
+        // multibyte characters are counted as one column.
+        let err = parse_sql_error("select 'äöü',\nx from no_such_table", 24);
+        assert_eq!((err.line(), err.column()), (2, 8));
+        // offset out of range
+        let err = parse_sql_error("select", 100);
+        assert_eq!((err.line(), err.column()), (1, 7));
+    }
+}
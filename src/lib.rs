@@ -29,7 +29,7 @@ instead. Some types were moved to a new module `sql_type`.
 Rust-oracle 0.4.x will include breaking changes about [query methods](https://github.com/kubo/rust-oracle/blob/master/docs/query-methods.md).
 The query methods with `as` may be renamed to and merged into methods
 without `as`. The `params` argument of `Connection.prepare` will be
-replaced with some kind of the builder pattern.
+removed. Use `Connection.statement` returning a builder instead.
 
 ## Change Log
 
@@ -62,6 +62,70 @@ data types, enable `chrono` feature:
 oracle = { version = "0.3.0", features = ["chrono"] }
 ```
 
+When you need to fetch or bind [time](https://docs.rs/time/0.2/time/)
+data types, enable `time` feature:
+
+```text
+[dependencies]
+oracle = { version = "0.3.0", features = ["time"] }
+```
+
+When you need to fetch or bind [rust_decimal](https://docs.rs/rust_decimal/1/rust_decimal/)
+or [bigdecimal](https://docs.rs/bigdecimal/0.1/bigdecimal/) data types
+as numbers, enable `rust_decimal` or `bigdecimal` feature:
+
+```text
+[dependencies]
+oracle = { version = "0.3.0", features = ["rust_decimal"] }
+```
+
+When you need to fetch or bind [uuid](https://docs.rs/uuid/0.8/uuid/)
+values stored as raw(16) or strings, enable `uuid` feature:
+
+```text
+[dependencies]
+oracle = { version = "0.3.0", features = ["uuid"] }
+```
+
+When you need to fetch or bind JSON text as [serde_json](https://docs.rs/serde_json/1/serde_json/)
+values or serializable types, enable `serde_json` feature:
+
+```text
+[dependencies]
+oracle = { version = "0.3.0", features = ["serde_json"] }
+```
+
+When you need to fetch query results as [Apache Arrow](https://docs.rs/arrow/54/arrow/)
+record batches or insert record batches into tables, enable `arrow` feature:
+
+```text
+[dependencies]
+oracle = { version = "0.3.0", features = ["arrow"] }
+```
+
+When you need to derive [FromSql][], [ToSql][] and [ToSqlNull][] for
+newtypes and unit enums, enable `derive` feature:
+
+```text
+[dependencies]
+oracle = { version = "0.3.0", features = ["derive"] }
+```
+
+```ignore
+use oracle::sql_type::{FromSql, ToSql, ToSqlNull};
+
+#[derive(FromSql, ToSql, ToSqlNull)]
+struct CustomerId(i64);
+
+#[derive(FromSql, ToSql, ToSqlNull)]
+enum Status {
+    #[oracle(rename = "A")]
+    Active,
+    #[oracle(rename = "I")]
+    Inactive,
+}
+```
+
 Then put this in your crate root:
 
 ```rust
@@ -267,7 +331,6 @@ required.
 * Connection pooling
 * Read and write LOB as stream
 * REF CURSOR, BOOLEAN
-* Scrollable cursors
 * Batch DML
 * Better Oracle object type support
 
@@ -279,6 +342,9 @@ Rust-oracle and ODPI-C bundled in rust-oracle are under the terms of:
 2. [the Apache License v 2.0](http://www.apache.org/licenses/LICENSE-2.0).
 
 [Rust]:                 https://www.rust-lang.org/
+[FromSql]: sql_type/trait.FromSql.html
+[ToSql]: sql_type/trait.ToSql.html
+[ToSqlNull]: sql_type/trait.ToSqlNull.html
 [ODPI-C]:               https://oracle.github.io/odpi/
 [ODPI-C installation document]: https://oracle.github.io/odpi/doc/installation.html
 [Oracle database]: https://www.oracle.com/database/index.html
@@ -293,6 +359,8 @@ use std::ptr;
 use std::result;
 use std::slice;
 
+#[cfg(feature = "arrow")]
+mod arrow;
 #[allow(dead_code)]
 #[allow(non_camel_case_types)]
 #[allow(non_snake_case)]
@@ -307,6 +375,11 @@ mod statement;
 mod util;
 mod version;
 
+#[cfg(feature = "arrow")]
+pub use crate::arrow::BatchInsertResult;
+#[cfg(feature = "arrow")]
+pub use crate::arrow::RecordBatches;
+pub use crate::connection::CancelHandle;
 pub use crate::connection::ConnStatus;
 pub use crate::connection::Connection;
 pub use crate::connection::Connector;
@@ -316,17 +389,29 @@ pub use crate::connection::StartupMode;
 pub use crate::error::DbError;
 pub use crate::error::Error;
 pub use crate::error::ParseOracleTypeError;
+pub use crate::error::ParseSqlError;
+pub use crate::row::OwnedResultSet;
+pub use crate::row::OwnedRow;
+pub use crate::row::PrefetchedRows;
 pub use crate::row::ResultSet;
 pub use crate::row::Row;
+pub use crate::row::RowBatch;
 pub use crate::row::RowValue;
+pub use crate::row::ScrollableResultSet;
 pub use crate::sql_value::SqlValue;
 pub use crate::statement::BindIndex;
+pub use crate::statement::BindParams;
 pub use crate::statement::ColumnIndex;
 pub use crate::statement::ColumnInfo;
+pub use crate::statement::OwnedStatement;
 pub use crate::statement::Statement;
+pub use crate::statement::StatementBuilder;
 pub use crate::statement::StatementType;
+#[allow(deprecated)]
 pub use crate::statement::StmtParam;
 pub use crate::version::Version;
+#[cfg(feature = "derive")]
+pub use oracle_derive::BindParams;
 
 use crate::binding::*;
 
@@ -17,19 +17,34 @@ use std::boxed::Box;
 use std::fmt;
 use std::iter::FusedIterator;
 use std::marker::PhantomData;
+use std::panic;
 use std::rc::Rc;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use std::vec;
 
+use crate::binding::dpiFetchMode;
+use crate::binding::DPI_MODE_FETCH_ABSOLUTE;
+use crate::binding::DPI_MODE_FETCH_FIRST;
+use crate::binding::DPI_MODE_FETCH_LAST;
+use crate::binding::DPI_MODE_FETCH_PRIOR;
+use crate::binding::DPI_MODE_FETCH_RELATIVE;
 use crate::sql_type::FromSql;
+use crate::sql_type::FromSqlRef;
+use crate::sql_type::Value;
+use crate::sql_value::BufferRowIndex;
 use crate::ColumnIndex;
 use crate::ColumnInfo;
 use crate::Connection;
 use crate::DpiConn;
+use crate::Error;
 use crate::Result;
 use crate::SqlValue;
 use crate::Statement;
 
 pub struct RowSharedData {
-    column_names: Vec<String>,
+    column_names: Arc<Vec<String>>,
     conn_handle: DpiConn,
 }
 
@@ -46,7 +61,7 @@ impl Row {
         column_values: Vec<SqlValue>,
     ) -> Result<Row> {
         let shared = RowSharedData {
-            column_names: column_names,
+            column_names: Arc::new(column_names),
             conn_handle: conn.handle.clone(),
         };
         Ok(Row {
@@ -65,6 +80,58 @@ impl Row {
         self.column_values[pos].get()
     }
 
+    /// Gets a reference to the column value at the specified index.
+    ///
+    /// Unlike [get][], this doesn't allocate memory. The returned value is
+    /// borrowed from the row. `T` is `str` or `[u8]`.
+    /// See [FromSqlRef][] for available Oracle types.
+    ///
+    /// ```no_run
+    /// # use oracle::*; fn try_main() -> Result<()> {
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    /// let mut stmt = conn.prepare("select ename from emp", &[])?;
+    ///
+    /// for result in stmt.query(&[])? {
+    ///     let row = result?;
+    ///     let ename: &str = row.get_ref("ENAME")?;
+    ///     if ename.starts_with("S") {
+    ///         println!("{}", ename);
+    ///     }
+    /// }
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    ///
+    /// [get]: #method.get
+    /// [FromSqlRef]: sql_type/trait.FromSqlRef.html
+    pub fn get_ref<I, T>(&self, colidx: I) -> Result<&T>
+    where
+        I: ColumnIndex,
+        T: FromSqlRef + ?Sized,
+    {
+        let pos = colidx.idx(&self.shared.column_names)?;
+        T::from_sql_ref(&self.column_values[pos])
+    }
+
+    /// Converts the row into an [OwnedRow][], which doesn't depend on
+    /// the statement and can be sent to other threads.
+    ///
+    /// Column values are converted to [Value][]s. Column names are
+    /// shared with other rows fetched by the same statement.
+    ///
+    /// [OwnedRow]: struct.OwnedRow.html
+    /// [Value]: sql_type/enum.Value.html
+    pub fn to_owned(&self) -> Result<OwnedRow> {
+        let values = self
+            .column_values
+            .iter()
+            .map(|val| val.get())
+            .collect::<Result<Vec<Value>>>()?;
+        Ok(OwnedRow {
+            column_names: self.shared.column_names.clone(),
+            values: values,
+        })
+    }
+
     /// Returns column values as a vector of SqlValue
     pub fn sql_values(&self) -> &[SqlValue] {
         &self.column_values
@@ -109,6 +176,47 @@ impl fmt::Debug for Row {
     }
 }
 
+/// Row detached from the statement
+///
+/// This is created by [Row.to_owned][] or [ResultSet.collect_owned][].
+/// Unlike [Row][], this is `Send` and `Sync` and may be stored after
+/// the statement is dropped.
+///
+/// [Row.to_owned]: struct.Row.html#method.to_owned
+/// [ResultSet.collect_owned]: struct.ResultSet.html#method.collect_owned
+/// [Row]: struct.Row.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnedRow {
+    column_names: Arc<Vec<String>>,
+    values: Vec<Value>,
+}
+
+impl OwnedRow {
+    /// Gets the column value at the specified index.
+    pub fn get<I>(&self, colidx: I) -> Result<&Value>
+    where
+        I: ColumnIndex,
+    {
+        let pos = colidx.idx(&self.column_names)?;
+        Ok(&self.values[pos])
+    }
+
+    /// Returns column names
+    pub fn column_names(&self) -> &[String] {
+        &self.column_names
+    }
+
+    /// Returns column values
+    pub fn values(&self) -> &[Value] {
+        &self.values
+    }
+
+    /// Converts the row into column values
+    pub fn into_values(self) -> Vec<Value> {
+        self.values
+    }
+}
+
 /// Result set
 pub struct ResultSet<'a, T>
 where
@@ -132,14 +240,20 @@ where
     }
 
     pub(crate) fn from_conn(conn: &'a Connection, sql: &str) -> Result<ResultSet<'a, T>> {
-        Ok(ResultSet {
+        Ok(ResultSet::from_boxed_stmt(Box::new(
+            conn.prepare(sql, &[])?,
+        )))
+    }
+
+    pub(crate) fn from_boxed_stmt(stmt: Box<Statement<'a>>) -> ResultSet<'a, T> {
+        ResultSet {
             stmt: None,
-            stmt_boxed: Some(Box::new(conn.prepare(sql, &[])?)),
+            stmt_boxed: Some(stmt),
             phantom: PhantomData,
-        })
+        }
     }
 
-    fn stmt(&self) -> &Statement {
+    pub(crate) fn stmt(&self) -> &Statement {
         if self.stmt.is_some() {
             self.stmt.as_ref().unwrap()
         } else if self.stmt_boxed.is_some() {
@@ -152,6 +266,136 @@ where
     pub fn column_info(&self) -> &[ColumnInfo] {
         &self.stmt().column_info
     }
+
+    /// Fetches the remaining rows as [OwnedRow][]s.
+    ///
+    /// Unlike collecting `ResultSet<Row>`, this doesn't copy fetch buffers
+    /// per row. The returned rows can be sent to other threads.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::*; fn try_main() -> Result<()> {
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    /// let rows = conn.query("select * from emp", &[])?.collect_owned()?;
+    /// let handle = std::thread::spawn(move || {
+    ///     for row in rows {
+    ///         println!("{:?}", row.values());
+    ///     }
+    /// });
+    /// handle.join().unwrap();
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    ///
+    /// [OwnedRow]: struct.OwnedRow.html
+    pub fn collect_owned(self) -> Result<Vec<OwnedRow>> {
+        let stmt = self.stmt();
+        let mut rows = Vec::new();
+        while let Some(row) = stmt.next() {
+            rows.push(row?.to_owned()?);
+        }
+        Ok(rows)
+    }
+
+    /// Fetches the next rows at a time.
+    ///
+    /// This fetches at most [fetch array size][] rows and returns them
+    /// as a [RowBatch][], whose column values are read directly from
+    /// fetch buffers without creating a [Row][] per row.
+    /// `None` is returned when no more rows are available.
+    ///
+    /// It may be used along with the `Iterator` implementation of `ResultSet`.
+    /// Rows fetched by either way are not fetched again by the other.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::*; fn try_main() -> Result<()> {
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    /// let mut stmt = conn.prepare("select empno, mgr from emp", &[])?;
+    /// let mut rows = stmt.query(&[])?;
+    ///
+    /// while let Some(batch) = rows.next_batch()? {
+    ///     for (empno, mgr) in batch.column::<i32, _>(0)?.zip(batch.column::<i32, _>("MGR")?) {
+    ///         // mgr is None when the column value is NULL.
+    ///         println!("{:?},{:?}", empno?, mgr?);
+    ///     }
+    /// }
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    ///
+    /// [fetch array size]: struct.StatementBuilder.html#method.fetch_array_size
+    /// [RowBatch]: struct.RowBatch.html
+    /// [Row]: struct.Row.html
+    pub fn next_batch(&mut self) -> Result<Option<RowBatch>> {
+        let stmt = self.stmt();
+        let (buffer_row_index, num_rows) = stmt.fetch_rows()?;
+        if num_rows == 0 {
+            return Ok(None);
+        }
+        Ok(Some(RowBatch {
+            // if stmt.row.is_none(), dpiStmt_fetchRows() returns non-zero.
+            row: stmt.row.as_ref().unwrap(),
+            buffer_row_index: buffer_row_index,
+            num_rows: num_rows,
+        }))
+    }
+}
+
+/// Rows fetched at a time by [ResultSet.next_batch][]
+///
+/// This borrows the result set. Therefore the next fetch is not allowed
+/// until the batch is dropped.
+///
+/// [ResultSet.next_batch]: struct.ResultSet.html#method.next_batch
+pub struct RowBatch<'a> {
+    row: &'a Row,
+    buffer_row_index: u32,
+    num_rows: u32,
+}
+
+impl<'a> RowBatch<'a> {
+    /// Returns the number of rows in the batch.
+    pub fn num_rows(&self) -> usize {
+        self.num_rows as usize
+    }
+
+    /// Returns an iterator over values in the column at the specified index.
+    ///
+    /// The value type is the first type parameter, such as `column::<i64, _>(0)`.
+    /// The iterator returns `Ok(None)` for NULL values and `Err` when
+    /// a value cannot be converted to `T`. Items are `Result` because
+    /// the conversion may fail per value, for example when a number
+    /// overflows `T`.
+    pub fn column<T, I>(&self, colidx: I) -> Result<impl Iterator<Item = Result<Option<T>>> + 'a>
+    where
+        T: FromSql + 'a,
+        I: ColumnIndex,
+    {
+        let pos = colidx.idx(&self.row.shared.column_names)?;
+        let mut val = self.row.column_values[pos].unsafely_clone();
+        let start = self.buffer_row_index;
+        Ok(
+            (start..(start + self.num_rows)).map(move |idx| -> Result<Option<T>> {
+                val.buffer_row_index = BufferRowIndex::Owned(idx);
+                if val.is_null()? {
+                    Ok(None)
+                } else {
+                    val.get().map(Some)
+                }
+            }),
+        )
+    }
+}
+
+impl<'a> fmt::Debug for RowBatch<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "RowBatch {{ columns: {:?}, num_rows: {} }}",
+            self.row.shared.column_names, self.num_rows
+        )
+    }
 }
 
 impl<'stmt, T> Iterator for ResultSet<'stmt, T>
@@ -188,6 +432,367 @@ where
     }
 }
 
+/// Scrollable result set
+///
+/// This is created by [Statement.query_scrollable][]. In addition to
+/// iterating rows forward, this moves the cursor to arbitrary positions.
+/// Methods moving the cursor return the row at the new position or `None`
+/// when the position is out of the result set.
+///
+/// [Statement.query_scrollable]: struct.Statement.html#method.query_scrollable
+pub struct ScrollableResultSet<'a, T>
+where
+    T: RowValue,
+{
+    rs: ResultSet<'a, T>,
+}
+
+impl<'a, T> ScrollableResultSet<'a, T>
+where
+    T: RowValue,
+{
+    pub(crate) fn new(rs: ResultSet<'a, T>) -> ScrollableResultSet<'a, T> {
+        ScrollableResultSet { rs: rs }
+    }
+
+    pub fn column_info(&self) -> &[ColumnInfo] {
+        self.rs.column_info()
+    }
+
+    /// Returns the position of the current row. The first row is 1.
+    /// Zero is returned before the first row is fetched.
+    pub fn current_position(&self) -> Result<u64> {
+        self.rs.stmt().row_count()
+    }
+
+    /// Moves the cursor to the first row and returns it.
+    pub fn scroll_first(&mut self) -> Result<Option<T>> {
+        self.scroll_to(DPI_MODE_FETCH_FIRST as dpiFetchMode, 0)
+    }
+
+    /// Moves the cursor to the last row and returns it.
+    pub fn scroll_last(&mut self) -> Result<Option<T>> {
+        self.scroll_to(DPI_MODE_FETCH_LAST as dpiFetchMode, 0)
+    }
+
+    /// Moves the cursor to the row before the current row and returns it.
+    pub fn scroll_prior(&mut self) -> Result<Option<T>> {
+        self.scroll_to(DPI_MODE_FETCH_PRIOR as dpiFetchMode, 0)
+    }
+
+    /// Moves the cursor to the `n`th row and returns it. The first row is 1.
+    pub fn scroll_absolute(&mut self, n: i32) -> Result<Option<T>> {
+        self.scroll_to(DPI_MODE_FETCH_ABSOLUTE as dpiFetchMode, n)
+    }
+
+    /// Moves the cursor `n` rows from the current row and returns the row there.
+    /// A negative `n` moves the cursor backward.
+    pub fn scroll_relative(&mut self, n: i32) -> Result<Option<T>> {
+        self.scroll_to(DPI_MODE_FETCH_RELATIVE as dpiFetchMode, n)
+    }
+
+    fn scroll_to(&mut self, mode: dpiFetchMode, offset: i32) -> Result<Option<T>> {
+        let stmt = self.rs.stmt();
+        if !stmt.scroll(mode, offset)? {
+            return Ok(None);
+        }
+        match stmt.next() {
+            Some(row) => Ok(Some(row?.get_as::<T>()?)),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<'a, T> Iterator for ScrollableResultSet<'a, T>
+where
+    T: RowValue,
+{
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rs.next()
+    }
+}
+
+impl<'a, T> fmt::Debug for ScrollableResultSet<'a, T>
+where
+    T: RowValue,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ScrollableResultSet {{ rs: {:?} }}", self.rs)
+    }
+}
+
+/// Result set which owns its statement and connection
+///
+/// This is created by [OwnedStatement.into_query_as][] and has no lifetime
+/// parameter unlike [ResultSet][].
+///
+/// [OwnedStatement.into_query_as]: struct.OwnedStatement.html#method.into_query_as
+/// [ResultSet]: struct.ResultSet.html
+pub struct OwnedResultSet<T>
+where
+    T: RowValue,
+{
+    // `rs` refers to the connection in `conn`. It must be dropped before `conn`.
+    rs: ResultSet<'static, T>,
+    conn: Arc<Connection>,
+}
+
+impl<T> OwnedResultSet<T>
+where
+    T: RowValue,
+{
+    pub(crate) fn new(rs: ResultSet<'static, T>, conn: Arc<Connection>) -> OwnedResultSet<T> {
+        OwnedResultSet { rs: rs, conn: conn }
+    }
+
+    /// Returns the connection which the result set belongs to.
+    pub fn connection(&self) -> &Arc<Connection> {
+        &self.conn
+    }
+
+    pub fn column_info(&self) -> &[ColumnInfo] {
+        self.rs.column_info()
+    }
+
+    /// See [ResultSet.next_batch][].
+    ///
+    /// [ResultSet.next_batch]: struct.ResultSet.html#method.next_batch
+    pub fn next_batch(&mut self) -> Result<Option<RowBatch>> {
+        self.rs.next_batch()
+    }
+
+    /// See [ResultSet.collect_owned][].
+    ///
+    /// [ResultSet.collect_owned]: struct.ResultSet.html#method.collect_owned
+    pub fn collect_owned(self) -> Result<Vec<OwnedRow>> {
+        self.rs.collect_owned()
+    }
+
+    /// Moves the result set to a worker thread which fetches rows
+    /// in the background.
+    ///
+    /// The worker thread converts fetched rows to [OwnedRow][]s and keeps
+    /// up to `depth` batches of them ready, where a batch has at most
+    /// [fetch array size][] rows. The returned [PrefetchedRows][] receives
+    /// the batches and iterates over the rows. Fetching in the worker thread
+    /// and processing in the current thread overlap. `depth` must be
+    /// greater than zero.
+    ///
+    /// An error in the worker thread is returned by the iterator, which ends
+    /// after that. When [PrefetchedRows][] is dropped or cancelled, the worker
+    /// thread stops after the fetch in progress and closes the statement.
+    ///
+    /// This isn't available for [ResultSet][] because it borrows the statement
+    /// or the connection, which cannot be moved to the worker thread.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::*; use std::sync::Arc; fn try_main() -> Result<()> {
+    /// let conn = Arc::new(Connection::connect("scott", "tiger", "")?);
+    /// let stmt = OwnedStatement::new(&conn, "select * from emp", &[])?;
+    /// let rows = stmt.into_query_as::<Row>(&[])?.prefetch_in_background(2)?;
+    /// for row_result in rows {
+    ///     let row = row_result?;
+    ///     println!("{:?}", row.values());
+    /// }
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    ///
+    /// [OwnedRow]: struct.OwnedRow.html
+    /// [fetch array size]: struct.StatementBuilder.html#method.fetch_array_size
+    /// [PrefetchedRows]: struct.PrefetchedRows.html
+    /// [ResultSet]: struct.ResultSet.html
+    pub fn prefetch_in_background(self, depth: usize) -> Result<PrefetchedRows> {
+        if depth == 0 {
+            return Err(Error::InvalidOperation(
+                "The prefetch depth must be greater than zero".to_string(),
+            ));
+        }
+        let column_info = self.column_info().to_vec();
+        let OwnedResultSet { mut rs, conn } = self;
+        // OwnedResultSet is always created from a boxed statement.
+        let stmt = rs.stmt_boxed.take().unwrap();
+        let worker_stmt = SendableStatement {
+            stmt: stmt,
+            conn: conn,
+        };
+        // The worker thread holds one more batch while it is blocked on
+        // sending it. Therefore the channel is bounded by `depth - 1`.
+        let (sender, receiver) = mpsc::sync_channel(depth - 1);
+        let worker = thread::spawn(move || {
+            let stmt = &worker_stmt.stmt;
+            loop {
+                let batch = fetch_owned_batch(stmt);
+                let is_last = match batch {
+                    Ok(Some(_)) => false,
+                    _ => true,
+                };
+                // The send fails when the receiver is dropped.
+                if sender.send(batch).is_err() || is_last {
+                    break;
+                }
+            }
+        });
+        Ok(PrefetchedRows {
+            column_info: column_info,
+            receiver: Some(receiver),
+            batch: Vec::new().into_iter(),
+            worker: Some(worker),
+        })
+    }
+}
+
+impl<T> Iterator for OwnedResultSet<T>
+where
+    T: RowValue,
+{
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rs.next()
+    }
+}
+
+impl<T> FusedIterator for OwnedResultSet<T> where T: RowValue {}
+
+impl<T> fmt::Debug for OwnedResultSet<T>
+where
+    T: RowValue,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "OwnedResultSet {{ rs: {:?} }}", self.rs)
+    }
+}
+
+// A statement and its connection moved to a worker thread.
+//
+// The statement isn't `Send` because column values share the buffer row
+// index by `Rc` and refer to define buffers by raw pointers. They are moved
+// along with the statement and no longer used in the original thread.
+// ODPI-C handles can be used by another thread because the context is
+// created in threaded mode.
+struct SendableStatement {
+    // `stmt` refers to the connection in `conn`. It must be dropped before `conn`.
+    stmt: Box<Statement<'static>>,
+    #[allow(dead_code)]
+    conn: Arc<Connection>,
+}
+
+unsafe impl Send for SendableStatement {}
+
+// Fetches at most fetch array size rows as owned rows.
+// It returns `Ok(None)` when no more rows are available.
+fn fetch_owned_batch(stmt: &Statement) -> Result<Option<Vec<OwnedRow>>> {
+    let size = stmt.fetch_array_size() as usize;
+    let mut rows = Vec::with_capacity(size);
+    while rows.len() < size {
+        match stmt.next() {
+            Some(row) => rows.push(row?.to_owned()?),
+            None => break,
+        }
+    }
+    Ok(if rows.is_empty() { None } else { Some(rows) })
+}
+
+/// Rows fetched in a background thread by [OwnedResultSet.prefetch_in_background][]
+///
+/// This is an iterator of [OwnedRow][]s.
+///
+/// [OwnedResultSet.prefetch_in_background]: struct.OwnedResultSet.html#method.prefetch_in_background
+/// [OwnedRow]: struct.OwnedRow.html
+pub struct PrefetchedRows {
+    column_info: Vec<ColumnInfo>,
+    receiver: Option<mpsc::Receiver<Result<Option<Vec<OwnedRow>>>>>,
+    batch: vec::IntoIter<OwnedRow>,
+    worker: Option<thread::JoinHandle<()>>,
+}
+
+impl PrefetchedRows {
+    pub fn column_info(&self) -> &[ColumnInfo] {
+        &self.column_info
+    }
+
+    /// Stops the worker thread and waits for it to finish.
+    ///
+    /// Rows already received are discarded and the iterator returns
+    /// `None` after this. When a fetch is in progress, this waits for it
+    /// to complete.
+    pub fn cancel(&mut self) {
+        self.batch = Vec::new().into_iter();
+        // The worker thread stops when it fails to send the next batch
+        // to the dropped receiver.
+        self.finish();
+    }
+
+    // Drops the receiver and joins the worker thread. A panic in the worker
+    // thread is propagated to the current thread.
+    fn finish(&mut self) {
+        self.receiver = None;
+        if let Some(worker) = self.worker.take() {
+            if let Err(payload) = worker.join() {
+                if !thread::panicking() {
+                    panic::resume_unwind(payload);
+                }
+            }
+        }
+    }
+}
+
+impl Iterator for PrefetchedRows {
+    type Item = Result<OwnedRow>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(row) = self.batch.next() {
+                return Some(Ok(row));
+            }
+            let received = match self.receiver {
+                Some(ref receiver) => receiver.recv(),
+                None => return None,
+            };
+            match received {
+                Ok(Ok(Some(batch))) => self.batch = batch.into_iter(),
+                Ok(Ok(None)) => {
+                    self.finish();
+                    return None;
+                }
+                Ok(Err(err)) => {
+                    self.finish();
+                    return Some(Err(err));
+                }
+                // The worker thread exited without sending the end.
+                Err(_) => {
+                    self.finish();
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+impl FusedIterator for PrefetchedRows {}
+
+impl Drop for PrefetchedRows {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
+
+impl fmt::Debug for PrefetchedRows {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "PrefetchedRows {{ column_info: {:?}, buffered_rows: {}, finished: {} }}",
+            self.column_info,
+            self.batch.len(),
+            self.worker.is_none()
+        )
+    }
+}
+
 /// A trait to get a row as specified type
 ///
 /// This is the return type of [Connection.query_row_as][],
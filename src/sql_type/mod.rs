@@ -20,24 +20,58 @@ use crate::Error;
 use crate::Result;
 use crate::SqlValue;
 
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal;
 #[cfg(feature = "chrono")]
 mod chrono;
 mod interval_ds;
 mod interval_ym;
+mod number;
 mod object;
 mod oracle_type;
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal;
+#[cfg(feature = "serde_json")]
+mod serde_json;
+#[cfg(feature = "time")]
+mod time;
 mod timestamp;
+#[cfg(feature = "uuid")]
+mod uuid;
+mod value;
 
 pub use self::interval_ds::IntervalDS;
 pub use self::interval_ym::IntervalYM;
+pub use self::number::OracleNumber;
+#[doc(hidden)]
+pub use self::object::check_attr_from_sql;
+#[doc(hidden)]
+pub use self::object::check_attr_types;
+#[doc(hidden)]
+pub use self::object::check_object_attrs;
+#[doc(hidden)]
+pub use self::object::check_object_type_name;
 pub use self::object::Collection;
 pub use self::object::Object;
+#[doc(hidden)]
+pub use self::object::ObjectAttrDef;
 pub use self::object::ObjectType;
 pub use self::object::ObjectTypeAttr;
 pub(crate) use self::object::ObjectTypeInternal;
 pub(crate) use self::oracle_type::NativeType;
 pub use self::oracle_type::OracleType;
+#[cfg(feature = "serde_json")]
+pub use self::serde_json::Json;
 pub use self::timestamp::Timestamp;
+pub use self::value::Value;
+#[cfg(feature = "derive")]
+pub use oracle_derive::FromSql;
+#[cfg(feature = "derive")]
+pub use oracle_derive::OracleObject;
+#[cfg(feature = "derive")]
+pub use oracle_derive::ToSql;
+#[cfg(feature = "derive")]
+pub use oracle_derive::ToSqlNull;
 
 /// Conversion from Oracle values to rust values.
 ///
@@ -47,12 +81,14 @@ pub use self::timestamp::Timestamp;
 /// | Oracle Type | Rust Type |
 /// | --- | --- |
 /// | character data types | String |
-/// | " | i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f64, f32 by using `String.parse()` |
+/// | " | i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f64, f32 by using `String.parse()` |
 /// | " | Vec\<u8> (The Oracle value must be in hexadecimal.) |
 /// | " | [Timestamp][] by `String.parse()` |
 /// | " | [IntervalDS][] by `String.parse()` |
 /// | " | [IntervalYM][] by `String.parse()` |
-/// | numeric data types | i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f64, f32 |
+/// | " | [OracleNumber][] by `String.parse()` |
+/// | numeric data types | i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f64, f32 |
+/// | " | [OracleNumber][] |
 /// | " | String |
 /// | raw | Vec\<u8> |
 /// | " | String (The Oracle value is converted to characters in hexadecimal.) |
@@ -67,6 +103,7 @@ pub use self::timestamp::Timestamp;
 /// | [Oracle collection] | [Collection][] |
 /// | " | String |
 /// | boolean (PL/SQL only) | bool |
+/// | any types except objects, BFILE and ref cursors | [Value][] |
 ///
 /// When `chrono` feature is enabled, the followings are added.
 ///
@@ -78,6 +115,31 @@ pub use self::timestamp::Timestamp;
 /// | " | [chrono::naive::NaiveDate][] |
 /// | interval day to second | [chrono::Duration][] |
 ///
+/// When `time` feature is enabled, the followings are added.
+///
+/// | Oracle Type | Rust Type |
+/// | --- | --- |
+/// | timestamp data types | [time::OffsetDateTime][] |
+/// | " | [time::PrimitiveDateTime][] |
+/// | " | [time::Date][] |
+/// | interval day to second | [time::Duration][] |
+///
+/// When `serde_json` feature is enabled, [serde_json::Value][] and
+/// [Json\<T>][Json] are added for character data types and CLOB
+/// holding JSON text.
+///
+/// When `uuid` feature is enabled, [uuid::Uuid][] is added for raw data
+/// types holding 16 bytes and character data types holding UUID strings.
+///
+/// When `rust_decimal` feature is enabled, [rust_decimal::Decimal][] is
+/// added for numeric and character data types. When `bigdecimal` feature
+/// is enabled, [bigdecimal::BigDecimal][] is added for them.
+///
+/// When `derive` feature is enabled, `#[derive(FromSql)]` is available
+/// for newtypes and unit enums. See [derive macros](derive.FromSql.html).
+/// `#[derive(OracleObject)]` maps a struct to an Oracle object type.
+/// See [here](derive.OracleObject.html).
+///
 /// This conversion is used also to get values from output parameters.
 ///
 /// [Oracle object]: https://docs.oracle.com/en/database/oracle/oracle-database/12.2/adobj/about-oracle-objects.html
@@ -85,19 +147,56 @@ pub use self::timestamp::Timestamp;
 /// [Timestamp]: struct.Timestamp.html
 /// [IntervalDS]: struct.IntervalDS.html
 /// [IntervalYM]: struct.IntervalYM.html
+/// [OracleNumber]: struct.OracleNumber.html
 /// [chrono::Date]: https://docs.rs/chrono/0.4/chrono/struct.Date.html
 /// [chrono::DateTime]: https://docs.rs/chrono/0.4/chrono/struct.DateTime.html
 /// [chrono::naive::NaiveDate]: https://docs.rs/chrono/0.4/chrono/naive/struct.NaiveDate.html
 /// [chrono::naive::NaiveDateTime]: https://docs.rs/chrono/0.4/chrono/naive/struct.NaiveDateTime.html
 /// [chrono::Duration]: https://docs.rs/chrono/0.4/chrono/struct.Duration.html
+/// [rust_decimal::Decimal]: https://docs.rs/rust_decimal/1/rust_decimal/struct.Decimal.html
+/// [bigdecimal::BigDecimal]: https://docs.rs/bigdecimal/0.1/bigdecimal/struct.BigDecimal.html
+/// [time::Date]: https://docs.rs/time/0.2/time/struct.Date.html
+/// [time::Duration]: https://docs.rs/time/0.2/time/struct.Duration.html
+/// [time::OffsetDateTime]: https://docs.rs/time/0.2/time/struct.OffsetDateTime.html
+/// [time::PrimitiveDateTime]: https://docs.rs/time/0.2/time/struct.PrimitiveDateTime.html
+/// [uuid::Uuid]: https://docs.rs/uuid/0.8/uuid/struct.Uuid.html
+/// [serde_json::Value]: https://docs.rs/serde_json/1/serde_json/enum.Value.html
+/// [Json]: struct.Json.html
 /// [Collection]: struct.Collection.html
 /// [Object]: struct.Object.html
+/// [Value]: enum.Value.html
 pub trait FromSql {
     fn from_sql(val: &SqlValue) -> Result<Self>
     where
         Self: Sized;
 }
 
+/// Conversion from Oracle values to references borrowed from them.
+///
+/// This is used by [Row.get_ref][] to get values without memory allocation.
+///
+/// | Rust Type | Oracle Type |
+/// | --- | --- |
+/// | str | char, nchar, varchar2, nvarchar2, long, number not fetched as integer |
+/// | [u8] | raw, long raw |
+///
+/// [Row.get_ref]: ../struct.Row.html#method.get_ref
+pub trait FromSqlRef {
+    fn from_sql_ref(val: &SqlValue) -> Result<&Self>;
+}
+
+impl FromSqlRef for str {
+    fn from_sql_ref(val: &SqlValue) -> Result<&str> {
+        val.as_str()
+    }
+}
+
+impl FromSqlRef for [u8] {
+    fn from_sql_ref(val: &SqlValue) -> Result<&[u8]> {
+        val.as_bytes()
+    }
+}
+
 /// A trait specifying Oracle type to bind a null value.
 ///
 /// This trait is used only when binding a `None` value of `Option<T>`.
@@ -106,7 +205,8 @@ pub trait FromSql {
 /// | Rust Type | Oracle Type |
 /// | --- | --- |
 /// | str, String | nvarchar2(0) |
-/// | i8, i16, i32, i64, u8, u16, u32, u64, f32, f64 | number |
+/// | i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, f32, f64 | number |
+/// | [OracleNumber][] | number |
 /// | Vec\<u8> | raw(0) |
 /// | bool | boolean (PL/SQL only) |
 /// | [Timestamp][] | timestamp(9) with time zone |
@@ -123,14 +223,42 @@ pub trait FromSql {
 /// | [chrono::naive::NaiveDateTime][] | timestamp(9) |
 /// | [chrono::Duration][] | interval day(9) to second(9) |
 ///
+/// When `time` feature is enabled, the followings are added.
+///
+/// | Rust Type | Oracle Type |
+/// | --- | --- |
+/// | [time::Date][] | date |
+/// | [time::OffsetDateTime][] | timestamp(9) with time zone |
+/// | [time::PrimitiveDateTime][] | timestamp(9) |
+/// | [time::Duration][] | interval day(9) to second(9) |
+///
+/// When `rust_decimal` or `bigdecimal` feature is enabled,
+/// [rust_decimal::Decimal][] or [bigdecimal::BigDecimal][] is bound as number.
+/// When `uuid` feature is enabled, [uuid::Uuid][] is bound as raw(16).
+/// When `serde_json` feature is enabled, [serde_json::Value][] and
+/// [Json\<T>][Json] are bound as JSON text in varchar2 or CLOB.
+///
+/// When `derive` feature is enabled, `#[derive(ToSqlNull)]` is available
+/// for newtypes and unit enums.
+///
 /// [Timestamp]: struct.Timestamp.html
 /// [IntervalDS]: struct.IntervalDS.html
 /// [IntervalYM]: struct.IntervalYM.html
+/// [OracleNumber]: struct.OracleNumber.html
 /// [chrono::Date]: https://docs.rs/chrono/0.4/chrono/struct.Date.html
 /// [chrono::DateTime]: https://docs.rs/chrono/0.4/chrono/struct.DateTime.html
 /// [chrono::naive::NaiveDate]: https://docs.rs/chrono/0.4/chrono/naive/struct.NaiveDate.html
 /// [chrono::naive::NaiveDateTime]: https://docs.rs/chrono/0.4/chrono/naive/struct.NaiveDateTime.html
 /// [chrono::Duration]: https://docs.rs/chrono/0.4/chrono/struct.Duration.html
+/// [rust_decimal::Decimal]: https://docs.rs/rust_decimal/1/rust_decimal/struct.Decimal.html
+/// [bigdecimal::BigDecimal]: https://docs.rs/bigdecimal/0.1/bigdecimal/struct.BigDecimal.html
+/// [time::Date]: https://docs.rs/time/0.2/time/struct.Date.html
+/// [time::Duration]: https://docs.rs/time/0.2/time/struct.Duration.html
+/// [time::OffsetDateTime]: https://docs.rs/time/0.2/time/struct.OffsetDateTime.html
+/// [time::PrimitiveDateTime]: https://docs.rs/time/0.2/time/struct.PrimitiveDateTime.html
+/// [uuid::Uuid]: https://docs.rs/uuid/0.8/uuid/struct.Uuid.html
+/// [serde_json::Value]: https://docs.rs/serde_json/1/serde_json/enum.Value.html
+/// [Json]: struct.Json.html
 pub trait ToSqlNull {
     fn oratype_for_null(conn: &Connection) -> Result<OracleType>;
 }
@@ -142,7 +270,8 @@ pub trait ToSqlNull {
 /// | Rust Type | Oracle Type | Oracle Value |
 /// | --- | --- | --- |
 /// | str, String | nvarchar2(length of the rust value) | The specified value |
-/// | i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64 | number | The specified value |
+/// | i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64 | number | The specified value |
+/// | [OracleNumber][] | number | The specified value |
 /// | Vec\<u8> | raw(length of the rust value) | The specified value |
 /// | bool | boolean (PL/SQL only) | The specified value |
 /// | [Timestamp][] | timestamp(9) with time zone | The specified value |
@@ -167,6 +296,24 @@ pub trait ToSqlNull {
 /// | [chrono::naive::NaiveDateTime][] | timestamp(9) |
 /// | [chrono::Duration][] | interval day(9) to second(9) |
 ///
+/// When `time` feature is enabled, the followings are added.
+///
+/// | Rust Type | Oracle Type |
+/// | --- | --- |
+/// | [time::Date][] | date |
+/// | [time::OffsetDateTime][] | timestamp(9) with time zone |
+/// | [time::PrimitiveDateTime][] | timestamp(9) |
+/// | [time::Duration][] | interval day(9) to second(9) |
+///
+/// When `rust_decimal` or `bigdecimal` feature is enabled,
+/// [rust_decimal::Decimal][] or [bigdecimal::BigDecimal][] is bound as number.
+/// When `uuid` feature is enabled, [uuid::Uuid][] is bound as raw(16).
+/// When `serde_json` feature is enabled, [serde_json::Value][] and
+/// [Json\<T>][Json] are bound as JSON text in varchar2 or CLOB.
+///
+/// When `derive` feature is enabled, `#[derive(ToSql)]` is available
+/// for newtypes and unit enums.
+///
 /// [Timestamp]: struct.Timestamp.html
 /// [IntervalDS]: struct.IntervalDS.html
 /// [IntervalYM]: struct.IntervalYM.html
@@ -174,6 +321,7 @@ pub trait ToSqlNull {
 /// [Collection.oracle_type]: struct.Collection.html#method.oracle_type
 /// [Object]: struct.Object.html
 /// [Object.oracle_type]: struct.Object.html#method.oracle_type
+/// [OracleNumber]: struct.OracleNumber.html
 /// [OracleType]: enum.OracleType.html
 /// [ToSqlNull]: trait.ToSqlNull.html
 /// [chrono::Date]: https://docs.rs/chrono/0.4/chrono/struct.Date.html
@@ -181,6 +329,15 @@ pub trait ToSqlNull {
 /// [chrono::naive::NaiveDate]: https://docs.rs/chrono/0.4/chrono/naive/struct.NaiveDate.html
 /// [chrono::naive::NaiveDateTime]: https://docs.rs/chrono/0.4/chrono/naive/struct.NaiveDateTime.html
 /// [chrono::Duration]: https://docs.rs/chrono/0.4/chrono/struct.Duration.html
+/// [rust_decimal::Decimal]: https://docs.rs/rust_decimal/1/rust_decimal/struct.Decimal.html
+/// [bigdecimal::BigDecimal]: https://docs.rs/bigdecimal/0.1/bigdecimal/struct.BigDecimal.html
+/// [time::Date]: https://docs.rs/time/0.2/time/struct.Date.html
+/// [time::Duration]: https://docs.rs/time/0.2/time/struct.Duration.html
+/// [time::OffsetDateTime]: https://docs.rs/time/0.2/time/struct.OffsetDateTime.html
+/// [time::PrimitiveDateTime]: https://docs.rs/time/0.2/time/struct.PrimitiveDateTime.html
+/// [uuid::Uuid]: https://docs.rs/uuid/0.8/uuid/struct.Uuid.html
+/// [serde_json::Value]: https://docs.rs/serde_json/1/serde_json/enum.Value.html
+/// [Json]: struct.Json.html
 ///
 pub trait ToSql {
     fn oratype(&self, conn: &Connection) -> Result<OracleType>;
@@ -236,9 +393,18 @@ impl_from_and_to_sql!(u16, to_u16, set_u16, OracleType::Number(0, 0));
 impl_from_and_to_sql!(u32, to_u32, set_u32, OracleType::Number(0, 0));
 impl_from_and_to_sql!(u64, to_u64, set_u64, OracleType::Number(0, 0));
 impl_from_and_to_sql!(usize, to_usize, set_usize, OracleType::Number(0, 0));
+impl_from_and_to_sql!(i128, to_i128, set_i128, OracleType::Number(0, 0));
+impl_from_and_to_sql!(u128, to_u128, set_u128, OracleType::Number(0, 0));
 impl_from_and_to_sql!(f64, to_f64, set_f64, OracleType::Number(0, 0));
 impl_from_and_to_sql!(f32, to_f32, set_f32, OracleType::Number(0, 0));
 impl_from_and_to_sql!(bool, to_bool, set_bool, OracleType::Boolean);
+impl_from_and_to_sql!(
+    OracleNumber,
+    to_oracle_number,
+    OracleNumber,
+    set_oracle_number,
+    OracleType::Number(0, 0)
+);
 impl_from_sql!(String, to_string);
 impl_from_sql!(Vec<u8>, to_bytes);
 impl_from_and_to_sql!(
@@ -333,6 +499,12 @@ impl<T: FromSql> FromSql for Option<T> {
     }
 }
 
+impl<T: ToSqlNull> ToSqlNull for Option<T> {
+    fn oratype_for_null(conn: &Connection) -> Result<OracleType> {
+        <T>::oratype_for_null(conn)
+    }
+}
+
 impl<T: ToSql + ToSqlNull> ToSql for Option<T> {
     fn oratype(&self, conn: &Connection) -> Result<OracleType> {
         match *self {
@@ -0,0 +1,654 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use std::cmp;
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops;
+use std::str;
+
+use crate::util::check_number_format;
+use crate::ParseOracleTypeError;
+
+/// The maximum number of significant decimal digits kept by OracleNumber
+const MAX_DIGITS: usize = 40;
+/// The absolute value must be less than 10^MAX_EXPONENT.
+const MAX_EXPONENT: i64 = 126;
+/// The absolute value must be greater than or equal to 10^(MIN_EXPONENT - 1).
+/// Smaller values are rounded to zero.
+const MIN_EXPONENT: i64 = -129;
+
+/// Oracle-specific [NUMBER][] data type holding decimal values exactly.
+///
+/// This keeps up to 40 significant decimal digits, which covers all values
+/// stored in NUMBER columns. Addition, subtraction and multiplication are
+/// exact as long as the result fits in 40 digits. Otherwise the result is
+/// rounded half away from zero as Oracle does. So is the quotient of division.
+///
+/// Operators `+`, `-`, `*` and `/` panic on overflow and division by zero
+/// in the same way as integer types. Use `checked_add`, `checked_sub`,
+/// `checked_mul` and `checked_div` to avoid panic.
+///
+/// [NUMBER]: https://docs.oracle.com/en/database/oracle/oracle-database/12.2/sqlrf/Data-Types.html#GUID-9401BC04-81C4-4CD5-99E7-C5E25C83F608
+///
+/// # Examples
+///
+/// ```
+/// # use oracle::*; use oracle::sql_type::*; fn try_main() -> Result<()> {
+/// let price: OracleNumber = "1234567890123456789.0123456789".parse()?;
+/// let quantity = OracleNumber::from(3);
+///
+/// // Arithmetic operations are exact.
+/// let total = price * quantity;
+/// assert_eq!(total.to_string(), "3703703670370370367.0370370367");
+///
+/// // Comparison
+/// assert!(total > price);
+///
+/// // The quotient is rounded to 40 significant digits.
+/// let third = OracleNumber::from(1) / quantity;
+/// assert_eq!(third.to_string(), "0.3333333333333333333333333333333333333333");
+/// # Ok(())} fn main() { try_main().unwrap(); }
+/// ```
+///
+/// Fetch and bind numbers.
+///
+/// ```no_run
+/// # use oracle::*; use oracle::sql_type::*; fn try_main() -> Result<()> {
+/// let conn = Connection::connect("scott", "tiger", "")?;
+///
+/// // Fetch OracleNumber
+/// let sql = "select 1/3 from dual";
+/// let num = conn.query_row_as::<OracleNumber>(sql, &[])?;
+/// assert_eq!(num.to_string(), "0.3333333333333333333333333333333333333333");
+///
+/// // Bind OracleNumber
+/// let sql = "begin :outval := :inval * 3; end;";
+/// let mut stmt = conn.prepare(sql, &[])?;
+/// stmt.execute(&[&OracleType::Number(0, 0), &num])?;
+/// let outval: OracleNumber = stmt.bind_value(1)?;
+/// assert_eq!(outval.to_string(), "0.9999999999999999999999999999999999999999");
+/// # Ok(())} fn main() { try_main().unwrap(); }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OracleNumber {
+    negative: bool,
+    /// the number of significant digits. Zero when the value is zero.
+    ndigits: u8,
+    /// significant digits without trailing zeros
+    digits: [u8; MAX_DIGITS],
+    /// The value is `0.d1d2d3... * 10^exponent`
+    exponent: i16,
+}
+
+impl OracleNumber {
+    /// Returns zero.
+    pub fn zero() -> OracleNumber {
+        OracleNumber {
+            negative: false,
+            ndigits: 0,
+            digits: [0; MAX_DIGITS],
+            exponent: 0,
+        }
+    }
+
+    /// Creates a number whose value is `0.d1d2d3... * 10^exponent`.
+    /// It returns `None` on overflow.
+    fn from_digits(negative: bool, digits: &[u8], exponent: i64) -> Option<OracleNumber> {
+        let mut exponent = exponent;
+        let mut start = 0;
+        while start < digits.len() && digits[start] == 0 {
+            start += 1;
+            exponent -= 1;
+        }
+        let digits = &digits[start..];
+        if digits.is_empty() {
+            return Some(OracleNumber::zero());
+        }
+        let mut buf = [0; MAX_DIGITS];
+        let mut len = cmp::min(digits.len(), MAX_DIGITS);
+        buf[..len].copy_from_slice(&digits[..len]);
+        if digits.len() > MAX_DIGITS && digits[MAX_DIGITS] >= 5 {
+            // round half away from zero
+            let mut idx = MAX_DIGITS;
+            loop {
+                if idx == 0 {
+                    // All digits were nine.
+                    buf[0] = 1;
+                    exponent += 1;
+                    break;
+                }
+                idx -= 1;
+                if buf[idx] == 9 {
+                    buf[idx] = 0;
+                } else {
+                    buf[idx] += 1;
+                    break;
+                }
+            }
+        }
+        while len > 0 && buf[len - 1] == 0 {
+            len -= 1;
+        }
+        if exponent > MAX_EXPONENT {
+            None
+        } else if exponent < MIN_EXPONENT {
+            Some(OracleNumber::zero())
+        } else {
+            Some(OracleNumber {
+                negative: negative,
+                ndigits: len as u8,
+                digits: buf,
+                exponent: exponent as i16,
+            })
+        }
+    }
+
+    fn digits(&self) -> &[u8] {
+        &self.digits[..self.ndigits as usize]
+    }
+
+    /// Returns the exponent of the least significant digit.
+    fn low_exponent(&self) -> i64 {
+        self.exponent as i64 - self.ndigits as i64
+    }
+
+    /// Returns digits placed from 10^(high - 1) down to 10^low.
+    fn aligned_digits(&self, high: i64, low: i64) -> Vec<u8> {
+        let mut vec = vec![0; (high - low) as usize];
+        let offset = (high - self.exponent as i64) as usize;
+        vec[offset..(offset + self.ndigits as usize)].copy_from_slice(self.digits());
+        vec
+    }
+
+    /// Returns true when the value is zero.
+    pub fn is_zero(&self) -> bool {
+        self.ndigits == 0
+    }
+
+    /// Returns true when the value is less than zero.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns the absolute value.
+    pub fn abs(&self) -> OracleNumber {
+        OracleNumber {
+            negative: false,
+            ..*self
+        }
+    }
+
+    /// Converts to the nearest `f64` value.
+    pub fn to_f64(&self) -> f64 {
+        self.to_string().parse().unwrap()
+    }
+
+    fn cmp_abs(&self, other: &OracleNumber) -> Ordering {
+        match (self.is_zero(), other.is_zero()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => self
+                .exponent
+                .cmp(&other.exponent)
+                .then_with(|| self.digits().cmp(other.digits())),
+        }
+    }
+
+    /// Adds absolute values.
+    fn add_abs(&self, other: &OracleNumber) -> (Vec<u8>, i64) {
+        let high = cmp::max(self.exponent, other.exponent) as i64 + 1;
+        let low = cmp::min(self.low_exponent(), other.low_exponent());
+        let mut lhs = self.aligned_digits(high, low);
+        let rhs = other.aligned_digits(high, low);
+        let mut carry = 0;
+        for idx in (0..lhs.len()).rev() {
+            let sum = lhs[idx] + rhs[idx] + carry;
+            lhs[idx] = sum % 10;
+            carry = sum / 10;
+        }
+        (lhs, high)
+    }
+
+    /// Subtracts the absolute value of `other` from that of `self`.
+    /// The former must not be greater than the latter.
+    fn sub_abs(&self, other: &OracleNumber) -> (Vec<u8>, i64) {
+        let high = self.exponent as i64;
+        let low = cmp::min(self.low_exponent(), other.low_exponent());
+        let mut lhs = self.aligned_digits(high, low);
+        let rhs = other.aligned_digits(high, low);
+        let mut borrow = 0;
+        for idx in (0..lhs.len()).rev() {
+            let sub = rhs[idx] + borrow;
+            if lhs[idx] >= sub {
+                lhs[idx] -= sub;
+                borrow = 0;
+            } else {
+                lhs[idx] = lhs[idx] + 10 - sub;
+                borrow = 1;
+            }
+        }
+        (lhs, high)
+    }
+
+    /// Computes `self + other`, returning `None` if overflow occurred.
+    pub fn checked_add(&self, other: &OracleNumber) -> Option<OracleNumber> {
+        if self.is_zero() {
+            return Some(*other);
+        }
+        if other.is_zero() {
+            return Some(*self);
+        }
+        if self.negative == other.negative {
+            let (digits, exponent) = self.add_abs(other);
+            OracleNumber::from_digits(self.negative, &digits, exponent)
+        } else {
+            match self.cmp_abs(other) {
+                Ordering::Equal => Some(OracleNumber::zero()),
+                Ordering::Greater => {
+                    let (digits, exponent) = self.sub_abs(other);
+                    OracleNumber::from_digits(self.negative, &digits, exponent)
+                }
+                Ordering::Less => {
+                    let (digits, exponent) = other.sub_abs(self);
+                    OracleNumber::from_digits(other.negative, &digits, exponent)
+                }
+            }
+        }
+    }
+
+    /// Computes `self - other`, returning `None` if overflow occurred.
+    pub fn checked_sub(&self, other: &OracleNumber) -> Option<OracleNumber> {
+        self.checked_add(&-*other)
+    }
+
+    /// Computes `self * other`, returning `None` if overflow occurred.
+    pub fn checked_mul(&self, other: &OracleNumber) -> Option<OracleNumber> {
+        if self.is_zero() || other.is_zero() {
+            return Some(OracleNumber::zero());
+        }
+        let lhs = self.digits();
+        let rhs = other.digits();
+        let mut product = vec![0u32; lhs.len() + rhs.len()];
+        for i in 0..lhs.len() {
+            for j in 0..rhs.len() {
+                product[i + j + 1] += lhs[i] as u32 * rhs[j] as u32;
+            }
+        }
+        for idx in (1..product.len()).rev() {
+            product[idx - 1] += product[idx] / 10;
+            product[idx] %= 10;
+        }
+        let digits = product.iter().map(|d| *d as u8).collect::<Vec<u8>>();
+        OracleNumber::from_digits(
+            self.negative != other.negative,
+            &digits,
+            self.exponent as i64 + other.exponent as i64,
+        )
+    }
+
+    /// Computes `self / other`, returning `None` if `other` is zero or
+    /// overflow occurred.
+    pub fn checked_div(&self, other: &OracleNumber) -> Option<OracleNumber> {
+        if other.is_zero() {
+            return None;
+        }
+        if self.is_zero() {
+            return Some(OracleNumber::zero());
+        }
+        let lhs = self.digits();
+        let rhs = other.digits();
+        // Long division yielding at least MAX_DIGITS + 1 significant digits.
+        // The last one is used to round the quotient.
+        let len = lhs.len() + MAX_DIGITS + 1 + rhs.len();
+        let mut quotient = Vec::with_capacity(len);
+        let mut remainder = Vec::with_capacity(rhs.len() + 1);
+        for idx in 0..len {
+            if remainder.is_empty() && idx >= lhs.len() {
+                break;
+            }
+            remainder.push(if idx < lhs.len() { lhs[idx] } else { 0 });
+            trim_leading_zeros(&mut remainder);
+            let mut digit = 0;
+            while cmp_int_digits(&remainder, rhs) != Ordering::Less {
+                sub_int_digits(&mut remainder, rhs);
+                digit += 1;
+            }
+            quotient.push(digit);
+        }
+        OracleNumber::from_digits(
+            self.negative != other.negative,
+            &quotient,
+            self.exponent as i64 - other.exponent as i64 + rhs.len() as i64,
+        )
+    }
+}
+
+/// Removes leading zeros in decimal digits of an integer.
+fn trim_leading_zeros(digits: &mut Vec<u8>) {
+    let zeros = digits.iter().take_while(|d| **d == 0).count();
+    digits.drain(..zeros);
+}
+
+/// Compares decimal digits of integers without leading zeros.
+fn cmp_int_digits(lhs: &[u8], rhs: &[u8]) -> Ordering {
+    lhs.len().cmp(&rhs.len()).then_with(|| lhs.cmp(rhs))
+}
+
+/// Subtracts `rhs` from `lhs`, which must not be less than `rhs`.
+fn sub_int_digits(lhs: &mut Vec<u8>, rhs: &[u8]) {
+    let offset = lhs.len() - rhs.len();
+    let mut borrow = 0;
+    for idx in (0..lhs.len()).rev() {
+        let sub = if idx >= offset { rhs[idx - offset] } else { 0 } + borrow;
+        if lhs[idx] >= sub {
+            lhs[idx] -= sub;
+            borrow = 0;
+        } else {
+            lhs[idx] = lhs[idx] + 10 - sub;
+            borrow = 1;
+        }
+    }
+    trim_leading_zeros(lhs);
+}
+
+impl Default for OracleNumber {
+    fn default() -> OracleNumber {
+        OracleNumber::zero()
+    }
+}
+
+impl cmp::PartialOrd for OracleNumber {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl cmp::Ord for OracleNumber {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.cmp_abs(other),
+            (true, true) => other.cmp_abs(self),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl ops::Neg for OracleNumber {
+    type Output = OracleNumber;
+
+    fn neg(self) -> OracleNumber {
+        if self.is_zero() {
+            self
+        } else {
+            OracleNumber {
+                negative: !self.negative,
+                ..self
+            }
+        }
+    }
+}
+
+impl ops::Add for OracleNumber {
+    type Output = OracleNumber;
+
+    fn add(self, other: OracleNumber) -> OracleNumber {
+        self.checked_add(&other)
+            .expect("attempt to add with overflow")
+    }
+}
+
+impl ops::Sub for OracleNumber {
+    type Output = OracleNumber;
+
+    fn sub(self, other: OracleNumber) -> OracleNumber {
+        self.checked_sub(&other)
+            .expect("attempt to subtract with overflow")
+    }
+}
+
+impl ops::Mul for OracleNumber {
+    type Output = OracleNumber;
+
+    fn mul(self, other: OracleNumber) -> OracleNumber {
+        self.checked_mul(&other)
+            .expect("attempt to multiply with overflow")
+    }
+}
+
+impl ops::Div for OracleNumber {
+    type Output = OracleNumber;
+
+    fn div(self, other: OracleNumber) -> OracleNumber {
+        if other.is_zero() {
+            panic!("attempt to divide by zero");
+        }
+        self.checked_div(&other)
+            .expect("attempt to divide with overflow")
+    }
+}
+
+impl ops::AddAssign for OracleNumber {
+    fn add_assign(&mut self, other: OracleNumber) {
+        *self = *self + other;
+    }
+}
+
+impl ops::SubAssign for OracleNumber {
+    fn sub_assign(&mut self, other: OracleNumber) {
+        *self = *self - other;
+    }
+}
+
+impl ops::MulAssign for OracleNumber {
+    fn mul_assign(&mut self, other: OracleNumber) {
+        *self = *self * other;
+    }
+}
+
+impl ops::DivAssign for OracleNumber {
+    fn div_assign(&mut self, other: OracleNumber) {
+        *self = *self / other;
+    }
+}
+
+macro_rules! impl_from_int {
+    ($type:ty) => {
+        impl From<$type> for OracleNumber {
+            fn from(val: $type) -> OracleNumber {
+                // Integers up to 128 bits fit in MAX_DIGITS.
+                val.to_string().parse().unwrap()
+            }
+        }
+    };
+}
+
+impl_from_int!(i8);
+impl_from_int!(i16);
+impl_from_int!(i32);
+impl_from_int!(i64);
+impl_from_int!(i128);
+impl_from_int!(isize);
+impl_from_int!(u8);
+impl_from_int!(u16);
+impl_from_int!(u32);
+impl_from_int!(u64);
+impl_from_int!(u128);
+impl_from_int!(usize);
+
+impl fmt::Display for OracleNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        if self.negative {
+            write!(f, "-")?;
+        }
+        let digits = self.digits();
+        let exponent = self.exponent as i64;
+        if exponent <= 0 {
+            write!(f, "0.")?;
+            for _ in exponent..0 {
+                write!(f, "0")?;
+            }
+            for digit in digits {
+                write!(f, "{}", digit)?;
+            }
+        } else if exponent >= digits.len() as i64 {
+            for digit in digits {
+                write!(f, "{}", digit)?;
+            }
+            for _ in (digits.len() as i64)..exponent {
+                write!(f, "0")?;
+            }
+        } else {
+            let (int_part, frac_part) = digits.split_at(exponent as usize);
+            for digit in int_part {
+                write!(f, "{}", digit)?;
+            }
+            write!(f, ".")?;
+            for digit in frac_part {
+                write!(f, "{}", digit)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for OracleNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "OracleNumber({})", self)
+    }
+}
+
+impl str::FromStr for OracleNumber {
+    type Err = ParseOracleTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseOracleTypeError::new("Oracle number");
+        check_number_format(s)?;
+        let (negative, s) = if s.starts_with('-') {
+            (true, &s[1..])
+        } else {
+            (false, s)
+        };
+        let (mantissa, exponent) = match s.find(|c| c == 'e' || c == 'E') {
+            Some(pos) => (&s[..pos], s[(pos + 1)..].parse::<i64>().map_err(|_| err())?),
+            None => (s, 0),
+        };
+        let (int_part, frac_part) = match mantissa.find('.') {
+            Some(pos) => (&mantissa[..pos], &mantissa[(pos + 1)..]),
+            None => (mantissa, ""),
+        };
+        let digits = int_part
+            .bytes()
+            .chain(frac_part.bytes())
+            .map(|b| b - b'0')
+            .collect::<Vec<u8>>();
+        let exponent = exponent
+            .checked_add(int_part.len() as i64)
+            .ok_or_else(err)?;
+        OracleNumber::from_digits(negative, &digits, exponent).ok_or_else(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn num(s: &str) -> OracleNumber {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let err = Err(ParseOracleTypeError::new("Oracle number"));
+        assert_eq!(num("0").to_string(), "0");
+        assert_eq!(num("-0").to_string(), "0");
+        assert_eq!(num("000123.4500").to_string(), "123.45");
+        assert_eq!(num("-123.45").to_string(), "-123.45");
+        assert_eq!(num("0.00012").to_string(), "0.00012");
+        assert_eq!(num("1.2e3").to_string(), "1200");
+        assert_eq!(num("1.2E-3").to_string(), "0.0012");
+        assert_eq!(num("12e+1").to_string(), "120");
+        assert_eq!(
+            num("12345678901234567890123456789012345678").to_string(),
+            "12345678901234567890123456789012345678"
+        );
+        assert_eq!(
+            num("0.12345678901234567890123456789012345678901234").to_string(),
+            "0.123456789012345678901234567890123456789"
+        );
+        assert_eq!(
+            num("0.99999999999999999999999999999999999999995").to_string(),
+            "1"
+        );
+        assert!(num("1e-130") > num("0"));
+        assert_eq!(num("1e-131").to_string(), "0");
+        assert!(num("9.99e125") > num("0"));
+        assert_eq!("1e126".parse::<OracleNumber>(), err);
+        assert_eq!("".parse::<OracleNumber>(), err);
+        assert_eq!("1.".parse::<OracleNumber>(), err);
+        assert_eq!("+1".parse::<OracleNumber>(), err);
+        assert_eq!("1e99999999999999999999".parse::<OracleNumber>(), err);
+    }
+
+    #[test]
+    fn compare() {
+        assert!(num("1") > num("0"));
+        assert!(num("-1") < num("0"));
+        assert!(num("1.5") > num("1.25"));
+        assert!(num("-1.5") < num("-1.25"));
+        assert!(num("10") > num("9.99"));
+        assert!(num("0.1") > num("0.099"));
+        assert_eq!(num("1.50"), num("1.5"));
+        assert_eq!(num("-0"), num("0"));
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(num("1.5") + num("2.25"), num("3.75"));
+        assert_eq!(num("1.5") + num("-2.25"), num("-0.75"));
+        assert_eq!(num("-1.5") + num("1.5"), num("0"));
+        assert_eq!(num("999") + num("1"), num("1000"));
+        assert_eq!(num("1e100") + num("1e-100"), num("1e100"));
+        assert_eq!(num("1000") - num("0.001"), num("999.999"));
+        assert_eq!(num("0.001") - num("1000"), num("-999.999"));
+        assert_eq!(num("12.5") * num("-0.4"), num("-5"));
+        assert_eq!(
+            num("99999999999999999999") * num("99999999999999999999"),
+            num("9999999999999999999800000000000000000001")
+        );
+        assert_eq!(num("1") / num("8"), num("0.125"));
+        assert_eq!(num("-10") / num("4"), num("-2.5"));
+        assert_eq!(
+            num("2") / num("3"),
+            num("0.6666666666666666666666666666666666666667")
+        );
+        assert_eq!(num("1e125").checked_mul(&num("10")), None);
+        assert_eq!(num("1").checked_div(&num("0")), None);
+        assert_eq!(OracleNumber::from(-123i32), num("-123"));
+        assert_eq!(
+            OracleNumber::from(std::u128::MAX),
+            num("340282366920938463463374607431768211455")
+        );
+        assert_eq!(
+            OracleNumber::from(std::u64::MAX),
+            num("18446744073709551615")
+        );
+    }
+}
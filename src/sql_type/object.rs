@@ -17,6 +17,7 @@ use std::cmp;
 use std::fmt;
 use std::ptr;
 use std::sync::Arc;
+use std::sync::Mutex;
 
 use crate::binding::*;
 use crate::chkerr;
@@ -259,6 +260,27 @@ impl Collection {
         chkerr!(self.ctxt, dpiObject_trim(self.handle, len as u32));
         Ok(())
     }
+
+    /// Returns the values of all elements in index order.
+    pub fn to_vec<T>(&self) -> Result<Vec<T>>
+    where
+        T: FromSql,
+    {
+        let mut vec = Vec::new();
+        let mut index = match self.first_index() {
+            Ok(index) => index,
+            Err(Error::NoDataFound) => return Ok(vec),
+            Err(err) => return Err(err),
+        };
+        loop {
+            vec.push(self.get(index)?);
+            index = match self.next_index(index) {
+                Ok(index) => index,
+                Err(Error::NoDataFound) => return Ok(vec),
+                Err(err) => return Err(err),
+            };
+        }
+    }
 }
 
 impl Clone for Collection {
@@ -437,6 +459,40 @@ impl Object {
         );
         Ok(())
     }
+
+    /// Gets elements of the specified collection attribute.
+    /// An empty vector is returned when the attribute is null.
+    pub fn get_vec<T>(&self, name: &str) -> Result<Vec<T>>
+    where
+        T: FromSql,
+    {
+        match self.get::<Option<Collection>>(name)? {
+            Some(coll) => coll.to_vec(),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Sets a new collection containing the specified values to
+    /// the specified collection attribute.
+    pub fn set_vec<T>(&mut self, name: &str, values: &[T]) -> Result<()>
+    where
+        T: ToSql,
+    {
+        let objtype = match self.type_attr(name)?.oratype {
+            OracleType::Object(ref objtype) => objtype.clone(),
+            ref oratype => {
+                return Err(Error::InvalidTypeConversion(
+                    "Vec".to_string(),
+                    oratype.to_string(),
+                ))
+            }
+        };
+        let mut coll = objtype.new_collection()?;
+        for value in values {
+            coll.push(value)?;
+        }
+        self.set(name, &coll)
+    }
 }
 
 impl Clone for Object {
@@ -712,6 +768,227 @@ impl fmt::Debug for ObjectTypeAttr {
     }
 }
 
+//
+// Helpers used by code generated by `#[derive(OracleObject)]`
+//
+
+/// Attribute definition of a struct deriving `OracleObject`
+#[doc(hidden)]
+pub struct ObjectAttrDef {
+    pub name: &'static str,
+    pub rust_type: &'static str,
+    pub oratype: OracleType,
+    pub is_collection: bool,
+}
+
+/// Calls `check` unless `key` has already been checked against the object type.
+///
+/// `key` identifies a Rust type and a conversion direction. The result is
+/// kept per object type so that types with the same name in other schemas
+/// or other connections are checked separately.
+#[doc(hidden)]
+pub fn check_object_attrs<F>(objtype: &ObjectType, key: &'static str, check: F) -> Result<()>
+where
+    F: FnOnce() -> Result<()>,
+{
+    if objtype.internal.checked_keys.lock()?.contains(&key) {
+        return Ok(());
+    }
+    check()?;
+    objtype.internal.checked_keys.lock()?.push(key);
+    Ok(())
+}
+
+/// Checks whether the object type is `type_name` specified by
+/// `#[oracle(type_name = "...")]`, which is `name` or `schema.name`.
+#[doc(hidden)]
+pub fn check_object_type_name(
+    objtype: &ObjectType,
+    type_name: &str,
+    rust_type: &'static str,
+) -> Result<()> {
+    let (schema, name) = match type_name.find('.') {
+        Some(pos) => (Some(&type_name[..pos]), &type_name[(pos + 1)..]),
+        None => (None, type_name),
+    };
+    let schema_matches = match schema {
+        Some(schema) => identifier_matches(schema, objtype.schema()),
+        None => true,
+    };
+    if schema_matches && identifier_matches(name, objtype.name()) {
+        Ok(())
+    } else {
+        Err(Error::InvalidTypeConversion(
+            objtype.to_string(),
+            rust_type.to_string(),
+        ))
+    }
+}
+
+// Compares an identifier in SQL with a name in the data dictionary.
+// Nonquoted identifiers are case-insensitive.
+fn identifier_matches(ident: &str, name: &str) -> bool {
+    if ident.len() >= 2 && ident.starts_with('"') && ident.ends_with('"') {
+        &ident[1..(ident.len() - 1)] == name
+    } else {
+        ident.eq_ignore_ascii_case(name)
+    }
+}
+
+/// Checks whether the object type has all attributes in `defs`
+/// and whether their types are compatible.
+#[doc(hidden)]
+pub fn check_attr_types(objtype: &ObjectType, defs: Vec<ObjectAttrDef>) -> Result<()> {
+    for def in defs {
+        let attr = find_attr(objtype, def.name)?;
+        let oratype = if def.is_collection {
+            match attr.oratype {
+                OracleType::Object(ref objtype) if objtype.is_collection() => {
+                    objtype.element_oracle_type().unwrap()
+                }
+                _ => return Err(attr_type_error(objtype, def.name, def.rust_type, attr)),
+            }
+        } else {
+            &attr.oratype
+        };
+        if !is_compatible_type(&def.oratype, oratype) {
+            return Err(attr_type_error(objtype, def.name, def.rust_type, attr));
+        }
+    }
+    Ok(())
+}
+
+/// Checks whether the object type has an attribute `name` whose value
+/// can be converted to `T`.
+///
+/// When `is_collection` is true, the element type of the collection
+/// attribute is checked instead.
+#[doc(hidden)]
+pub fn check_attr_from_sql<T>(
+    objtype: &ObjectType,
+    name: &'static str,
+    rust_type: &'static str,
+    is_collection: bool,
+) -> Result<()>
+where
+    T: FromSql,
+{
+    let attr = find_attr(objtype, name)?;
+    let oratype = if is_collection {
+        match attr.oratype {
+            OracleType::Object(ref objtype) if objtype.is_collection() => {
+                objtype.element_oracle_type().unwrap()
+            }
+            _ => return Err(attr_type_error(objtype, name, rust_type, attr)),
+        }
+    } else {
+        &attr.oratype
+    };
+    // Convert a null value of the attribute type. Conversion errors are
+    // reported before null checks, so only incompatible types fail here.
+    let mut data = dpiData {
+        isNull: 1,
+        ..Default::default()
+    };
+    let val = SqlValue::from_oratype(objtype.internal.ctxt, oratype, &mut data)?;
+    match T::from_sql(&val) {
+        Err(Error::InvalidTypeConversion(_, _)) => {
+            Err(attr_type_error(objtype, name, rust_type, attr))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn attr_type_error(
+    objtype: &ObjectType,
+    name: &str,
+    rust_type: &str,
+    attr: &ObjectTypeAttr,
+) -> Error {
+    Error::InvalidTypeConversion(
+        format!("{}.{} {}", objtype, name, attr.oratype),
+        rust_type.to_string(),
+    )
+}
+
+fn find_attr<'a>(objtype: &'a ObjectType, name: &str) -> Result<&'a ObjectTypeAttr> {
+    if objtype.is_collection() {
+        return Err(Error::InvalidOperation(format!(
+            "{} isn't object type.",
+            objtype
+        )));
+    }
+    for attr in objtype.attributes() {
+        if attr.name() == name {
+            return Ok(attr);
+        }
+    }
+    Err(Error::InvalidAttributeName(name.to_string()))
+}
+
+#[derive(PartialEq)]
+enum TypeCategory {
+    Character,
+    Numeric,
+    Binary,
+    DateTime,
+    IntervalDS,
+    IntervalYM,
+    Boolean,
+    Object,
+    Other,
+}
+
+fn type_category(oratype: &OracleType) -> TypeCategory {
+    match *oratype {
+        OracleType::Varchar2(_)
+        | OracleType::NVarchar2(_)
+        | OracleType::Char(_)
+        | OracleType::NChar(_)
+        | OracleType::Rowid
+        | OracleType::Long
+        | OracleType::CLOB
+        | OracleType::NCLOB => TypeCategory::Character,
+        OracleType::BinaryFloat
+        | OracleType::BinaryDouble
+        | OracleType::Number(_, _)
+        | OracleType::Float(_)
+        | OracleType::Int64
+        | OracleType::UInt64 => TypeCategory::Numeric,
+        OracleType::Raw(_) | OracleType::LongRaw | OracleType::BLOB => TypeCategory::Binary,
+        OracleType::Date
+        | OracleType::Timestamp(_)
+        | OracleType::TimestampTZ(_)
+        | OracleType::TimestampLTZ(_) => TypeCategory::DateTime,
+        OracleType::IntervalDS(_, _) => TypeCategory::IntervalDS,
+        OracleType::IntervalYM(_) => TypeCategory::IntervalYM,
+        OracleType::Boolean => TypeCategory::Boolean,
+        OracleType::Object(_) => TypeCategory::Object,
+        OracleType::BFILE | OracleType::RefCursor => TypeCategory::Other,
+    }
+}
+
+/// Returns true when values of a rust type whose `ToSqlNull` type is
+/// `expected` can be converted from/to values of `actual`.
+fn is_compatible_type(expected: &OracleType, actual: &OracleType) -> bool {
+    match (expected, actual) {
+        (&OracleType::Object(ref expected), &OracleType::Object(ref actual)) => {
+            expected == actual
+                || (expected.schema() == actual.schema() && expected.name() == actual.name())
+        }
+        _ => {
+            let expected = type_category(expected);
+            let actual = type_category(actual);
+            // Character types such as String are converted from/to scalar types.
+            expected == actual
+                || (expected == TypeCategory::Character
+                    && actual != TypeCategory::Boolean
+                    && actual != TypeCategory::Object
+                    && actual != TypeCategory::Other)
+        }
+    }
+}
+
 //
 // ObjectTypeInternal
 //
@@ -723,6 +1000,7 @@ pub(crate) struct ObjectTypeInternal {
     name: String,
     elem_oratype: Option<OracleType>,
     attrs: Vec<ObjectTypeAttr>,
+    checked_keys: Mutex<Vec<&'static str>>,
 }
 
 impl ObjectTypeInternal {
@@ -771,6 +1049,7 @@ impl ObjectTypeInternal {
             name: to_rust_str(info.name, info.nameLength),
             elem_oratype: elem_oratype,
             attrs: attrs,
+            checked_keys: Mutex::new(Vec::new()),
         })
     }
 }
@@ -0,0 +1,55 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use crate::sql_type::FromSql;
+use crate::sql_type::OracleNumber;
+use crate::sql_type::OracleType;
+use crate::sql_type::ToSql;
+use crate::sql_type::ToSqlNull;
+use crate::Connection;
+use crate::Error;
+use crate::Result;
+use crate::SqlValue;
+use rust_decimal::Decimal;
+
+//
+// rust_decimal::Decimal
+//
+
+impl FromSql for Decimal {
+    fn from_sql(val: &SqlValue) -> Result<Decimal> {
+        let num = val.to_oracle_number()?;
+        num.to_string()
+            .parse()
+            .map_err(|err| Error::ParseError(Box::new(err)))
+    }
+}
+
+impl ToSqlNull for Decimal {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Number(0, 0))
+    }
+}
+
+impl ToSql for Decimal {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Number(0, 0))
+    }
+
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        let num: OracleNumber = self.to_string().parse()?;
+        val.set_oracle_number(&num)
+    }
+}
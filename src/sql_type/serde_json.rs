@@ -0,0 +1,136 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use crate::sql_type::FromSql;
+use crate::sql_type::OracleType;
+use crate::sql_type::ToSql;
+use crate::sql_type::ToSqlNull;
+use crate::Connection;
+use crate::Error;
+use crate::Result;
+use crate::SqlValue;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json_crate::Value;
+
+/// A wrapper type to fetch and bind a value serialized as JSON text.
+///
+/// This is available when `serde_json` feature is enabled.
+/// JSON text is fetched from character data types and CLOB. It is bound
+/// as CLOB so that the length of the text isn't limited.
+///
+/// # Examples
+///
+/// ```ignore
+/// use oracle::sql_type::Json;
+/// use oracle::Connection;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     name: String,
+///     retries: u32,
+/// }
+///
+/// let conn = Connection::connect("scott", "tiger", "")?;
+///
+/// // Bind Config as JSON text.
+/// let config = Config { name: "batch".into(), retries: 3 };
+/// conn.execute("insert into configs values (:1, :2)", &[&1, &Json(config)])?;
+///
+/// // Fetch JSON text as Config.
+/// let sql = "select doc from configs where id = :1";
+/// let config = conn.query_row_as::<Json<Config>>(sql, &[&1])?.0;
+/// assert_eq!(config.retries, 3);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Json<T>(pub T);
+
+fn json_from_sql<T>(val: &SqlValue) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    let s = val.to_string()?;
+    serde_json_crate::from_str(&s).map_err(|err| Error::ParseError(Box::new(err)))
+}
+
+fn json_to_sql<T>(json: &T, val: &mut SqlValue) -> Result<()>
+where
+    T: Serialize,
+{
+    let s = serde_json_crate::to_string(json).map_err(|err| Error::ParseError(Box::new(err)))?;
+    val.set_string(&s)
+}
+
+//
+// serde_json::Value
+//
+
+impl FromSql for Value {
+    fn from_sql(val: &SqlValue) -> Result<Value> {
+        json_from_sql(val)
+    }
+}
+
+impl ToSqlNull for Value {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::CLOB)
+    }
+}
+
+impl ToSql for Value {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::CLOB)
+    }
+
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        json_to_sql(self, val)
+    }
+}
+
+//
+// Json<T>
+//
+
+impl<T> FromSql for Json<T>
+where
+    T: DeserializeOwned,
+{
+    fn from_sql(val: &SqlValue) -> Result<Json<T>> {
+        Ok(Json(json_from_sql(val)?))
+    }
+}
+
+impl<T> ToSqlNull for Json<T>
+where
+    T: Serialize,
+{
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::CLOB)
+    }
+}
+
+impl<T> ToSql for Json<T>
+where
+    T: Serialize,
+{
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::CLOB)
+    }
+
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        json_to_sql(&self.0, val)
+    }
+}
@@ -0,0 +1,235 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use crate::sql_type::FromSql;
+use crate::sql_type::IntervalDS;
+use crate::sql_type::OracleType;
+use crate::sql_type::Timestamp;
+use crate::sql_type::ToSql;
+use crate::sql_type::ToSqlNull;
+use crate::Connection;
+use crate::Error;
+use crate::Result;
+use crate::SqlValue;
+use time::Date;
+use time::Duration;
+use time::OffsetDateTime;
+use time::PrimitiveDateTime;
+use time::Time;
+use time::UtcOffset;
+
+//
+// time::OffsetDateTime
+//
+
+impl FromSql for OffsetDateTime {
+    fn from_sql(val: &SqlValue) -> Result<OffsetDateTime> {
+        let ts = val.to_timestamp()?;
+        let offset = UtcOffset::seconds(ts.tz_offset());
+        Ok(primitive_date_time_from_sql(&ts)?.assume_offset(offset))
+    }
+}
+
+impl ToSqlNull for OffsetDateTime {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::TimestampTZ(9))
+    }
+}
+
+impl ToSql for OffsetDateTime {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::TimestampTZ(9))
+    }
+
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        let ts = Timestamp::new(
+            self.year(),
+            self.month() as u32,
+            self.day() as u32,
+            self.hour() as u32,
+            self.minute() as u32,
+            self.second() as u32,
+            self.nanosecond(),
+        );
+        let ts = ts.and_tz_offset(self.offset().as_seconds());
+        val.set_timestamp(&ts)
+    }
+}
+
+//
+// time::PrimitiveDateTime
+//
+
+fn primitive_date_time_from_sql(ts: &Timestamp) -> Result<PrimitiveDateTime> {
+    let date = date_from_sql(ts)?;
+    match Time::try_from_hms_nano(
+        ts.hour() as u8,
+        ts.minute() as u8,
+        ts.second() as u8,
+        ts.nanosecond(),
+    ) {
+        Ok(time) => Ok(PrimitiveDateTime::new(date, time)),
+        Err(_) => Err(Error::OutOfRange(format!(
+            "invalid time: {}:{}:{}.{}",
+            ts.hour(),
+            ts.minute(),
+            ts.second(),
+            ts.nanosecond()
+        ))),
+    }
+}
+
+impl FromSql for PrimitiveDateTime {
+    fn from_sql(val: &SqlValue) -> Result<PrimitiveDateTime> {
+        let ts = val.to_timestamp()?;
+        primitive_date_time_from_sql(&ts)
+    }
+}
+
+impl ToSqlNull for PrimitiveDateTime {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Timestamp(9))
+    }
+}
+
+impl ToSql for PrimitiveDateTime {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Timestamp(9))
+    }
+
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        let ts = Timestamp::new(
+            self.year(),
+            self.month() as u32,
+            self.day() as u32,
+            self.hour() as u32,
+            self.minute() as u32,
+            self.second() as u32,
+            self.nanosecond(),
+        );
+        val.set_timestamp(&ts)
+    }
+}
+
+//
+// time::Date
+//
+
+fn date_from_sql(ts: &Timestamp) -> Result<Date> {
+    match Date::try_from_ymd(ts.year(), ts.month() as u8, ts.day() as u8) {
+        Ok(date) => Ok(date),
+        Err(_) => Err(Error::OutOfRange(format!(
+            "invalid month and/or day: {}-{}-{}",
+            ts.year(),
+            ts.month(),
+            ts.day()
+        ))),
+    }
+}
+
+impl FromSql for Date {
+    fn from_sql(val: &SqlValue) -> Result<Date> {
+        let ts = val.to_timestamp()?;
+        date_from_sql(&ts)
+    }
+}
+
+impl ToSqlNull for Date {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Date)
+    }
+}
+
+impl ToSql for Date {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Date)
+    }
+
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        let ts = Timestamp::new(
+            self.year(),
+            self.month() as u32,
+            self.day() as u32,
+            0,
+            0,
+            0,
+            0,
+        );
+        val.set_timestamp(&ts)
+    }
+}
+
+//
+// time::Duration
+//
+
+impl FromSql for Duration {
+    fn from_sql(val: &SqlValue) -> Result<Duration> {
+        let err =
+            |it: IntervalDS| Error::OutOfRange(format!("Duration overflow: {}", it.to_string()));
+        let it = val.to_interval_ds()?;
+        let d = Duration::zero();
+        let d = d
+            .checked_add(Duration::days(it.days() as i64))
+            .ok_or(err(it))?;
+        let d = d
+            .checked_add(Duration::hours(it.hours() as i64))
+            .ok_or(err(it))?;
+        let d = d
+            .checked_add(Duration::minutes(it.minutes() as i64))
+            .ok_or(err(it))?;
+        let d = d
+            .checked_add(Duration::seconds(it.seconds() as i64))
+            .ok_or(err(it))?;
+        let d = d
+            .checked_add(Duration::nanoseconds(it.nanoseconds() as i64))
+            .ok_or(err(it))?;
+        Ok(d)
+    }
+}
+
+impl ToSqlNull for Duration {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::IntervalDS(9, 9))
+    }
+}
+
+impl ToSql for Duration {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::IntervalDS(9, 9))
+    }
+
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        let secs = self.whole_seconds();
+        let nsecs = self.subsec_nanoseconds();
+        let days = secs / (24 * 60 * 60);
+        let secs = secs % (24 * 60 * 60);
+        let hours = secs / (60 * 60);
+        let secs = secs % (60 * 60);
+        let minutes = secs / 60;
+        let secs = secs % 60;
+        if days.abs() >= 1000000000 {
+            return Err(Error::OutOfRange(format!("too large days: {:?}", self)));
+        }
+        let it = IntervalDS::new(
+            days as i32,
+            hours as i32,
+            minutes as i32,
+            secs as i32,
+            nsecs as i32,
+        );
+        val.set_interval_ds(&it)
+    }
+}
@@ -0,0 +1,66 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use crate::sql_type::FromSql;
+use crate::sql_type::OracleType;
+use crate::sql_type::ToSql;
+use crate::sql_type::ToSqlNull;
+use crate::Connection;
+use crate::Error;
+use crate::Result;
+use crate::SqlValue;
+use uuid::Uuid;
+
+fn is_binary_type(oratype: &OracleType) -> bool {
+    match *oratype {
+        OracleType::Raw(_) | OracleType::LongRaw | OracleType::BLOB => true,
+        _ => false,
+    }
+}
+
+//
+// uuid::Uuid
+//
+
+impl FromSql for Uuid {
+    fn from_sql(val: &SqlValue) -> Result<Uuid> {
+        let result = if is_binary_type(val.oracle_type()?) {
+            Uuid::from_slice(&val.to_bytes()?)
+        } else {
+            Uuid::parse_str(&val.to_string()?)
+        };
+        result.map_err(|err| Error::ParseError(Box::new(err)))
+    }
+}
+
+impl ToSqlNull for Uuid {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Raw(16))
+    }
+}
+
+impl ToSql for Uuid {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Raw(16))
+    }
+
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        if is_binary_type(val.oracle_type()?) {
+            val.set_bytes(self.as_bytes())
+        } else {
+            val.set_string(&self.to_string())
+        }
+    }
+}
@@ -0,0 +1,124 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use crate::binding::DPI_MAX_INT64_PRECISION;
+use crate::sql_type::FromSql;
+use crate::sql_type::IntervalDS;
+use crate::sql_type::IntervalYM;
+use crate::sql_type::OracleType;
+use crate::sql_type::Timestamp;
+use crate::Error;
+use crate::Result;
+use crate::SqlValue;
+
+/// Owned column value detached from fetch buffers
+///
+/// Unlike [SqlValue][], this is `Send` and `Sync` and may be stored after
+/// the statement is dropped. See [Row.to_owned][].
+///
+/// | Oracle Type | Value |
+/// | --- | --- |
+/// | null value | `Value::Null` |
+/// | number(p) where p < 18 | `Value::Int` |
+/// | other number, float | `Value::Number` (text representation) |
+/// | binary_float, binary_double | `Value::Float` |
+/// | char, nchar, varchar2, nvarchar2, long, CLOB, NCLOB | `Value::Str` |
+/// | raw, long raw, BLOB | `Value::Bytes` |
+/// | date, timestamp, timestamp with (local) time zone | `Value::Timestamp` |
+/// | interval day to second | `Value::IntervalDS` |
+/// | interval year to month | `Value::IntervalYM` |
+/// | rowid | `Value::Rowid` |
+/// | boolean (PL/SQL only) | `Value::Bool` |
+///
+/// Other Oracle types such as objects, BFILE and ref cursors cannot be converted.
+///
+/// [SqlValue]: ../struct.SqlValue.html
+/// [Row.to_owned]: ../struct.Row.html#method.to_owned
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// Null value
+    Null,
+    /// Integer
+    Int(i64),
+    /// Unsigned integer, used for values bound as u64
+    UInt(u64),
+    /// Number in text representation to keep its precision
+    Number(String),
+    /// Floating point number
+    Float(f64),
+    /// Boolean
+    Bool(bool),
+    /// String
+    Str(String),
+    /// Binary data
+    Bytes(Vec<u8>),
+    /// Date and timestamp
+    Timestamp(Timestamp),
+    /// Interval day to second
+    IntervalDS(IntervalDS),
+    /// Interval year to month
+    IntervalYM(IntervalYM),
+    /// Rowid in text representation
+    Rowid(String),
+}
+
+impl Value {
+    /// Returns `true` when the value is `Value::Null`.
+    pub fn is_null(&self) -> bool {
+        match *self {
+            Value::Null => true,
+            _ => false,
+        }
+    }
+}
+
+impl FromSql for Value {
+    fn from_sql(val: &SqlValue) -> Result<Value> {
+        if val.is_null()? {
+            return Ok(Value::Null);
+        }
+        Ok(match *val.oracle_type()? {
+            OracleType::Number(prec, 0) if 0 < prec && prec < DPI_MAX_INT64_PRECISION as u8 => {
+                Value::Int(val.get()?)
+            }
+            OracleType::Int64 => Value::Int(val.get()?),
+            OracleType::UInt64 => Value::UInt(val.get()?),
+            OracleType::Number(_, _) | OracleType::Float(_) => Value::Number(val.get()?),
+            OracleType::BinaryFloat | OracleType::BinaryDouble => Value::Float(val.get()?),
+            OracleType::Varchar2(_)
+            | OracleType::NVarchar2(_)
+            | OracleType::Char(_)
+            | OracleType::NChar(_)
+            | OracleType::Long
+            | OracleType::CLOB
+            | OracleType::NCLOB => Value::Str(val.get()?),
+            OracleType::Raw(_) | OracleType::LongRaw | OracleType::BLOB => Value::Bytes(val.get()?),
+            OracleType::Date
+            | OracleType::Timestamp(_)
+            | OracleType::TimestampTZ(_)
+            | OracleType::TimestampLTZ(_) => Value::Timestamp(val.get()?),
+            OracleType::IntervalDS(_, _) => Value::IntervalDS(val.get()?),
+            OracleType::IntervalYM(_) => Value::IntervalYM(val.get()?),
+            OracleType::Rowid => Value::Rowid(val.get()?),
+            OracleType::Boolean => Value::Bool(val.get()?),
+            ref oratype => {
+                return Err(Error::InvalidTypeConversion(
+                    oratype.to_string(),
+                    "Value".to_string(),
+                ))
+            }
+        })
+    }
+}
@@ -14,6 +14,7 @@
 //-----------------------------------------------------------------------------
 
 use std::cell::RefCell;
+use std::error;
 use std::fmt;
 use std::ptr;
 use std::rc::Rc;
@@ -29,6 +30,7 @@ use crate::sql_type::IntervalYM;
 use crate::sql_type::NativeType;
 use crate::sql_type::Object;
 use crate::sql_type::ObjectType;
+use crate::sql_type::OracleNumber;
 use crate::sql_type::OracleType;
 use crate::sql_type::Timestamp;
 use crate::sql_type::ToSql;
@@ -109,6 +111,31 @@ macro_rules! define_fn_set_int {
     }
 }
 
+/// Parses a decimal integer string. When the string is an integer
+/// which doesn't fit in the type, it returns `Error::OutOfRange`.
+fn parse_int<T>(s: &str, type_name: &str) -> Result<T>
+where
+    T: str::FromStr,
+    T::Err: error::Error + Send + Sync + 'static,
+{
+    s.parse().map_err(|err| {
+        let digits = if s.starts_with('-') { &s[1..] } else { s };
+        if !digits.is_empty() && digits.bytes().all(|b| b'0' <= b && b <= b'9') {
+            Error::OutOfRange(format!("{} overflow: {}", type_name, s))
+        } else {
+            Error::ParseError(Box::new(err))
+        }
+    })
+}
+
+// The maximum size of a LOB value converted to `String` or `Vec<u8>`
+// and the column name used in the error message.
+#[derive(Clone)]
+pub(crate) struct LobSizeLimit {
+    pub(crate) max_size: u64,
+    pub(crate) column_name: String,
+}
+
 pub enum BufferRowIndex {
     Shared(Rc<RefCell<u32>>),
     Owned(u32),
@@ -140,6 +167,7 @@ pub struct SqlValue {
     pub(crate) buffer_row_index: BufferRowIndex,
     keep_bytes: Vec<u8>,
     keep_dpiobj: *mut dpiObject,
+    pub(crate) lob_size_limit: Option<LobSizeLimit>,
 }
 
 impl SqlValue {
@@ -155,6 +183,7 @@ impl SqlValue {
             buffer_row_index: BufferRowIndex::Owned(0),
             keep_bytes: Vec::new(),
             keep_dpiobj: ptr::null_mut(),
+            lob_size_limit: None,
         }
     }
 
@@ -175,6 +204,7 @@ impl SqlValue {
             buffer_row_index: BufferRowIndex::Owned(0),
             keep_bytes: Vec::new(),
             keep_dpiobj: ptr::null_mut(),
+            lob_size_limit: None,
         })
     }
 
@@ -272,6 +302,20 @@ impl SqlValue {
         unsafe { self.data.offset(self.buffer_row_index() as isize) }
     }
 
+    // Returns the length in bytes of the value at the buffer row index `idx`
+    // or `None` when it is null. The native_type must be NativeType::Char
+    // or NativeType::Raw.
+    pub(crate) fn bytes_len_at(&self, idx: u32) -> Option<u32> {
+        unsafe {
+            let data = self.data.offset(idx as isize);
+            if (*data).isNull != 0 {
+                None
+            } else {
+                Some((*dpiData_getBytes(data)).length)
+            }
+        }
+    }
+
     pub(crate) fn native_type_num(&self) -> dpiNativeTypeNum {
         self.native_type.to_native_type_num()
     }
@@ -359,6 +403,42 @@ impl SqlValue {
         }
     }
 
+    /// Gets the SQL value as a string slice borrowed from the internal buffer.
+    ///
+    /// Unlike `get::<String>()`, this doesn't allocate memory.
+    /// The Oracle type must be `CHAR`, `NCHAR`, `VARCHAR2`, `NVARCHAR2`,
+    /// `LONG` or `NUMBER` not fetched as an integer. Otherwise, this returns
+    /// `Error::InvalidTypeConversion`. `Error::NullValue` is returned when
+    /// the SQL value is null.
+    ///
+    /// The returned value refers to the buffer of the SQL value. It must be
+    /// released before the SQL value is modified.
+    pub fn as_str(&self) -> Result<&str> {
+        match self.native_type {
+            NativeType::Char | NativeType::Number => {
+                let bytes = self.get_bytes_ref_unchecked()?;
+                str::from_utf8(bytes).map_err(|err| Error::ParseError(Box::new(err)))
+            }
+            _ => self.invalid_conversion_to_rust_type("&str"),
+        }
+    }
+
+    /// Gets the SQL value as a byte slice borrowed from the internal buffer.
+    ///
+    /// Unlike `get::<Vec<u8>>()`, this doesn't allocate memory.
+    /// The Oracle type must be `RAW` or `LONG RAW`. Otherwise, this returns
+    /// `Error::InvalidTypeConversion`. `Error::NullValue` is returned when
+    /// the SQL value is null.
+    ///
+    /// The returned value refers to the buffer of the SQL value. It must be
+    /// released before the SQL value is modified.
+    pub fn as_bytes(&self) -> Result<&[u8]> {
+        match self.native_type {
+            NativeType::Raw => self.get_bytes_ref_unchecked(),
+            _ => self.invalid_conversion_to_rust_type("&[u8]"),
+        }
+    }
+
     fn get_string(&self) -> Result<String> {
         match self.native_type {
             NativeType::Char | NativeType::Number => self.get_string_unchecked(),
@@ -410,6 +490,30 @@ impl SqlValue {
         }
     }
 
+    /// Gets the SQL value as &[u8] in the internal buffer. The native_type
+    /// must be NativeType::Char, NativeType::Number or NativeType::Raw.
+    /// Otherwise, this may cause access violation.
+    fn get_bytes_ref_unchecked(&self) -> Result<&[u8]> {
+        self.check_not_null()?;
+        unsafe {
+            let bytes = dpiData_getBytes(self.data());
+            Ok(to_rust_slice((*bytes).ptr, (*bytes).length))
+        }
+    }
+
+    /// Gets the SQL value as string. The native_type must be
+    /// NativeType::Rowid. Otherwise, this may cause access violation.
+    fn get_rowid_as_string_unchecked(&self) -> Result<String> {
+        self.check_not_null()?;
+        let mut ptr = ptr::null();
+        let mut len = 0;
+        chkerr!(
+            self.ctxt,
+            dpiRowid_getStringValue((*self.data()).value.asRowid, &mut ptr, &mut len)
+        );
+        Ok(to_rust_str(ptr, len))
+    }
+
     /// Gets the SQL value as Vec<u8>. The native_type must be
     /// NativeType::Raw. Otherwise, this may cause access violation.
     fn get_raw_unchecked(&self) -> Result<Vec<u8>> {
@@ -464,6 +568,18 @@ impl SqlValue {
         }
     }
 
+    // Checks that a LOB value of `size` bytes can be converted to
+    // `String` or `Vec<u8>` or fetched inline.
+    pub(crate) fn check_lob_size(&self, size: u64) -> Result<()> {
+        match self.lob_size_limit {
+            Some(ref limit) if size > limit.max_size => Err(Error::OutOfRange(format!(
+                "The LOB value of column {} is {} bytes, which exceeds the maximum size {}",
+                limit.column_name, size, limit.max_size
+            ))),
+            _ => Ok(()),
+        }
+    }
+
     fn get_clob_as_string_unchecked(&self) -> Result<String> {
         self.check_not_null()?;
         const READ_CHAR_SIZE: u64 = 8192;
@@ -476,6 +592,7 @@ impl SqlValue {
             dpiLob_getBufferSize(lob, total_char_size, &mut total_byte_size);
             dpiLob_getBufferSize(lob, READ_CHAR_SIZE, &mut bufsiz);
         }
+        self.check_lob_size(total_byte_size)?;
         let mut result = String::with_capacity(total_byte_size as usize);
         let mut buf = vec![0u8; bufsiz as usize];
         let bufptr = buf.as_mut_ptr() as *mut i8;
@@ -500,6 +617,7 @@ impl SqlValue {
         unsafe {
             dpiLob_getSize(lob, &mut total_size);
         }
+        self.check_lob_size(total_size)?;
         let mut result: Vec<u8> = Vec::with_capacity(total_size as usize);
         let mut read_len = total_size;
         chkerr!(
@@ -526,6 +644,7 @@ impl SqlValue {
         unsafe {
             dpiLob_getSize(lob, &mut total_size);
         }
+        self.check_lob_size(total_size * 2)?;
         let mut result = String::with_capacity((total_size * 2) as usize);
         let mut buf = vec![0u8; READ_SIZE as usize];
         let bufptr = buf.as_mut_ptr() as *mut i8;
@@ -815,6 +934,42 @@ impl SqlValue {
         }
     }
 
+    /// Gets the SQL value as i128. The Oracle type must be
+    /// numeric or string (excluding LOB) types.
+    pub(crate) fn to_i128(&self) -> Result<i128> {
+        match self.native_type {
+            NativeType::Int64 => Ok(self.get_i64_unchecked()? as i128),
+            NativeType::UInt64 => Ok(self.get_u64_unchecked()? as i128),
+            NativeType::Float => flt_to_int!(self.get_f32_unchecked()?, f32, i128),
+            NativeType::Double => flt_to_int!(self.get_f64_unchecked()?, f64, i128),
+            NativeType::Char | NativeType::CLOB | NativeType::Number => {
+                parse_int(&self.get_string()?, "i128")
+            }
+            _ => self.invalid_conversion_to_rust_type("i128"),
+        }
+    }
+
+    /// Gets the SQL value as u128. The Oracle type must be
+    /// numeric or string (excluding LOB) types.
+    pub(crate) fn to_u128(&self) -> Result<u128> {
+        match self.native_type {
+            NativeType::Int64 => {
+                let val = self.get_i64_unchecked()?;
+                if val < 0 {
+                    return Err(Error::OutOfRange(format!("u128 overflow: {}", val)));
+                }
+                Ok(val as u128)
+            }
+            NativeType::UInt64 => Ok(self.get_u64_unchecked()? as u128),
+            NativeType::Float => flt_to_int!(self.get_f32_unchecked()?, f32, u128),
+            NativeType::Double => flt_to_int!(self.get_f64_unchecked()?, f64, u128),
+            NativeType::Char | NativeType::CLOB | NativeType::Number => {
+                parse_int(&self.get_string()?, "u128")
+            }
+            _ => self.invalid_conversion_to_rust_type("u128"),
+        }
+    }
+
     /// Gets the SQL value as f32. The Oracle type must be
     /// numeric or string (excluding LOB) types.
     pub(crate) fn to_f32(&self) -> Result<f32> {
@@ -845,6 +1000,21 @@ impl SqlValue {
         }
     }
 
+    /// Gets the SQL value as OracleNumber. The Oracle type must be
+    /// numeric or string (excluding LOB) types.
+    pub(crate) fn to_oracle_number(&self) -> Result<OracleNumber> {
+        match self.native_type {
+            NativeType::Int64 => Ok(OracleNumber::from(self.get_i64_unchecked()?)),
+            NativeType::UInt64 => Ok(OracleNumber::from(self.get_u64_unchecked()?)),
+            NativeType::Float => Ok(self.get_f32_unchecked()?.to_string().parse()?),
+            NativeType::Double => Ok(self.get_f64_unchecked()?.to_string().parse()?),
+            NativeType::Char | NativeType::CLOB | NativeType::Number => {
+                Ok(self.get_string()?.parse()?)
+            }
+            _ => self.invalid_conversion_to_rust_type("OracleNumber"),
+        }
+    }
+
     /// Gets the SQL value as string. ...
     pub(crate) fn to_string(&self) -> Result<String> {
         match self.native_type {
@@ -859,6 +1029,7 @@ impl SqlValue {
             NativeType::IntervalYM => Ok(self.get_interval_ym_unchecked()?.to_string()),
             NativeType::CLOB => self.get_clob_as_string_unchecked(),
             NativeType::BLOB => self.get_blob_as_hex_string_unchecked(),
+            NativeType::Rowid => self.get_rowid_as_string_unchecked(),
             NativeType::Object(ref objtype) => {
                 if objtype.is_collection() {
                     Ok(self.get_collection_unchecked(objtype)?.to_string())
@@ -998,6 +1169,65 @@ impl SqlValue {
         /// numeric or string (excluding LOB) types.
         : set_f64, f64);
 
+    /// Sets i128 to the SQL value. The Oracle type must be
+    /// numeric or string (excluding LOB) types.
+    pub(crate) fn set_i128(&mut self, val: &i128) -> Result<()> {
+        match self.native_type {
+            NativeType::Int64 => {
+                if *val < i64::min_value() as i128 || *val > i64::max_value() as i128 {
+                    return Err(Error::OutOfRange(format!("i64 overflow: {}", val)));
+                }
+                self.set_i64_unchecked(*val as i64)
+            }
+            NativeType::UInt64 => {
+                if *val < 0 || *val > u64::max_value() as i128 {
+                    return Err(Error::OutOfRange(format!("u64 overflow: {}", val)));
+                }
+                self.set_u64_unchecked(*val as u64)
+            }
+            NativeType::Float => self.set_f32_unchecked(*val as f32),
+            NativeType::Double => self.set_f64_unchecked(*val as f64),
+            NativeType::Char | NativeType::Number => self.set_string_unchecked(&val.to_string()),
+            _ => self.invalid_conversion_from_rust_type("i128"),
+        }
+    }
+
+    /// Sets u128 to the SQL value. The Oracle type must be
+    /// numeric or string (excluding LOB) types.
+    pub(crate) fn set_u128(&mut self, val: &u128) -> Result<()> {
+        match self.native_type {
+            NativeType::Int64 => {
+                if *val > i64::max_value() as u128 {
+                    return Err(Error::OutOfRange(format!("i64 overflow: {}", val)));
+                }
+                self.set_i64_unchecked(*val as i64)
+            }
+            NativeType::UInt64 => {
+                if *val > u64::max_value() as u128 {
+                    return Err(Error::OutOfRange(format!("u64 overflow: {}", val)));
+                }
+                self.set_u64_unchecked(*val as u64)
+            }
+            NativeType::Float => self.set_f32_unchecked(*val as f32),
+            NativeType::Double => self.set_f64_unchecked(*val as f64),
+            NativeType::Char | NativeType::Number => self.set_string_unchecked(&val.to_string()),
+            _ => self.invalid_conversion_from_rust_type("u128"),
+        }
+    }
+
+    /// Sets OracleNumber to the SQL value. The Oracle type must be
+    /// numeric or string (excluding LOB) types.
+    pub(crate) fn set_oracle_number(&mut self, val: &OracleNumber) -> Result<()> {
+        match self.native_type {
+            NativeType::Int64 => self.set_i64_unchecked(val.to_string().parse()?),
+            NativeType::UInt64 => self.set_u64_unchecked(val.to_string().parse()?),
+            NativeType::Float => self.set_f32_unchecked(val.to_f64() as f32),
+            NativeType::Double => self.set_f64_unchecked(val.to_f64()),
+            NativeType::Char | NativeType::Number => self.set_string_unchecked(&val.to_string()),
+            _ => self.invalid_conversion_from_rust_type("OracleNumber"),
+        }
+    }
+
     /// Sets &str to the SQL value. ...
     pub(crate) fn set_string(&mut self, val: &str) -> Result<()> {
         match self.native_type {
@@ -1094,6 +1324,7 @@ impl SqlValue {
             buffer_row_index: BufferRowIndex::Owned(0),
             keep_bytes: Vec::new(),
             keep_dpiobj: ptr::null_mut(),
+            lob_size_limit: self.lob_size_limit.clone(),
         }
     }
 }
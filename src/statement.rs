@@ -13,27 +13,42 @@
 // (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
 //-----------------------------------------------------------------------------
 
+use std::cell::Cell;
 use std::cell::RefCell;
+use std::cmp;
+use std::collections::HashMap;
 use std::fmt;
+use std::hash::BuildHasher;
+use std::mem;
 use std::ptr;
 use std::rc::Rc;
+use std::slice;
+use std::sync::Arc;
+use std::time::Duration;
 
 use crate::binding::*;
 use crate::chkerr;
-use crate::new_odpi_str;
+use crate::error::dpi_error_code;
+use crate::error::error_from_context;
+use crate::error::error_from_dpi_error;
 use crate::private;
 use crate::sql_type::FromSql;
+use crate::sql_type::ObjectType;
 use crate::sql_type::OracleType;
 use crate::sql_type::ToSql;
 use crate::sql_value::BufferRowIndex;
+use crate::sql_value::LobSizeLimit;
 use crate::to_odpi_str;
 use crate::to_rust_str;
 use crate::Connection;
+use crate::DbError;
 use crate::Error;
+use crate::OwnedResultSet;
 use crate::Result;
 use crate::ResultSet;
 use crate::Row;
 use crate::RowValue;
+use crate::ScrollableResultSet;
 use crate::SqlValue;
 
 // https://docs.oracle.com/en/database/oracle/oracle-database/19/lnoci/handle-and-descriptor-attributes.html#GUID-A251CF91-EB9F-4DBC-8BB8-FB5EA92C20DE
@@ -41,7 +56,17 @@ const SQLFNCODE_CREATE_TYPE: u16 = 77;
 const SQLFNCODE_ALTER_TYPE: u16 = 80;
 const SQLFNCODE_DROP_TYPE: u16 = 78;
 
+// DPI-1027: scroll operation would go out of the result set
+const DPI_ERR_SCROLL_OUT_OF_RS: i32 = 1027;
+
 /// Parameters to prepare Statement.
+///
+/// This is deprecated. Use [StatementBuilder][] returned by
+/// [Connection.statement][] instead.
+///
+/// [StatementBuilder]: struct.StatementBuilder.html
+/// [Connection.statement]: struct.Connection.html#method.statement
+#[deprecated(since = "0.3.3", note = "Use Connection.statement() instead")]
 #[derive(Debug, Clone, PartialEq)]
 pub enum StmtParam {
     /// The array size used for performing fetches.
@@ -56,10 +81,23 @@ pub enum StmtParam {
     /// `StmtParam::FetchArraySize(1)`.
     FetchArraySize(u32),
 
-    /// Reserved for when statement caching is supported.
+    /// Searches the statement cache by the tag.
+    ///
+    /// When a statement put into the cache with the tag is found,
+    /// it is reused and the SQL text is ignored. Otherwise, the statement
+    /// is prepared from the SQL text as usual. In both cases, the statement
+    /// is put into the cache with the tag when it is closed or dropped.
+    /// See also [Statement.close_with_tag][].
+    ///
+    /// [Statement.close_with_tag]: struct.Statement.html#method.close_with_tag
     Tag(String),
 
-    /// Reserved for when scrollable cursors are supported.
+    /// Makes the statement scrollable.
+    ///
+    /// A scrollable statement may be executed by [Statement.query_scrollable][]
+    /// to move the cursor forward and backward.
+    ///
+    /// [Statement.query_scrollable]: struct.Statement.html#method.query_scrollable
     Scrollable,
 }
 
@@ -162,6 +200,458 @@ impl fmt::Display for StatementType {
     }
 }
 
+// A callback to determine the data type to define a column of queries.
+#[derive(Clone)]
+pub(crate) struct OutputTypeHandler(
+    Arc<dyn Fn(&ColumnInfo) -> Result<Option<OracleType>> + Send + Sync>,
+);
+
+impl OutputTypeHandler {
+    pub(crate) fn new<F>(handler: F) -> OutputTypeHandler
+    where
+        F: Fn(&ColumnInfo) -> Result<Option<OracleType>> + Send + Sync + 'static,
+    {
+        OutputTypeHandler(Arc::new(handler))
+    }
+
+    fn call(&self, info: &ColumnInfo) -> Result<Option<OracleType>> {
+        (self.0)(info)
+    }
+}
+
+impl fmt::Debug for OutputTypeHandler {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "OutputTypeHandler")
+    }
+}
+
+/// Builder data type to create Statement.
+///
+/// This is created by [Connection.statement][]. [build][] creates a
+/// [Statement][] and [build_owned][] creates an [OwnedStatement][].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use oracle::*; fn try_main() -> Result<()> {
+/// let conn = Connection::connect("scott", "tiger", "")?;
+/// // fetch top 10 rows.
+/// let mut stmt = conn
+///     .statement("select * from (select empno, ename from emp order by empno) where rownum <= 10")
+///     .fetch_array_size(10)
+///     .build()?;
+/// for row_result in stmt.query_as::<(i32, String)>(&[])? {
+///     let (empno, ename) = row_result?;
+///     println!("empno: {}, ename: {}", empno, ename);
+/// }
+/// # Ok(())} fn main() { try_main().unwrap(); }
+/// ```
+///
+/// [Connection.statement]: struct.Connection.html#method.statement
+/// [build]: #method.build
+/// [build_owned]: #method.build_owned
+/// [Statement]: struct.Statement.html
+/// [OwnedStatement]: struct.OwnedStatement.html
+#[derive(Debug, Clone)]
+pub struct StatementBuilder<'conn, 'sql> {
+    conn: &'conn Connection,
+    sql: &'sql str,
+    fetch_array_size: Option<u32>,
+    scrollable: bool,
+    tag: String,
+    exclude_from_cache: bool,
+    commit_on_success: Option<bool>,
+    call_timeout: Option<Duration>,
+    output_type_handler: Option<OutputTypeHandler>,
+    fetch_lobs_inline: bool,
+    max_lob_size: Option<u64>,
+    fetch_memory_budget: Option<usize>,
+    grow_fetch_array_size: bool,
+}
+
+impl<'conn, 'sql> StatementBuilder<'conn, 'sql> {
+    pub(crate) fn new(conn: &'conn Connection, sql: &'sql str) -> StatementBuilder<'conn, 'sql> {
+        StatementBuilder {
+            conn: conn,
+            sql: sql,
+            fetch_array_size: None,
+            scrollable: false,
+            tag: "".into(),
+            exclude_from_cache: false,
+            commit_on_success: None,
+            call_timeout: None,
+            output_type_handler: None,
+            fetch_lobs_inline: false,
+            max_lob_size: None,
+            fetch_memory_budget: None,
+            grow_fetch_array_size: false,
+        }
+    }
+
+    /// Changes the array size used for performing fetches.
+    ///
+    /// This specifies the number of rows allocated before performing
+    /// fetches. The default value is 100. Higher value reduces
+    /// the number of network round trips to fetch rows but requires
+    /// more memory. The preferable value depends on the query and
+    /// the environment.
+    ///
+    /// If the query returns only one row, you should use
+    /// `fetch_array_size(1)`.
+    pub fn fetch_array_size(&mut self, size: u32) -> &mut StatementBuilder<'conn, 'sql> {
+        self.fetch_array_size = Some(size);
+        self
+    }
+
+    /// Determines the fetch array size from the memory used by define buffers.
+    ///
+    /// When the query is executed first, the array size is set to the number
+    /// of rows whose define buffers fit in `bytes`. At least one row is
+    /// fetched at a time however wide a row is. Note that values fetched into
+    /// dynamically allocated buffers such as `LONG` columns aren't counted.
+    ///
+    /// This overrides [fetch_array_size][]. The chosen size is available
+    /// by [Statement.fetch_array_size][].
+    ///
+    /// [fetch_array_size]: #method.fetch_array_size
+    /// [Statement.fetch_array_size]: struct.Statement.html#method.fetch_array_size
+    pub fn fetch_memory_budget(&mut self, bytes: usize) -> &mut StatementBuilder<'conn, 'sql> {
+        self.fetch_memory_budget = Some(bytes);
+        self
+    }
+
+    /// Grows the fetch array size determined by [fetch_memory_budget][]
+    /// when `b` is true.
+    ///
+    /// The first round trip of each execution fetches at most 100 rows and
+    /// then the array size is doubled after each round trip up to the size
+    /// fitting in the memory budget. It makes the first rows arrive early and
+    /// reduces round trips of large result sets. This is ignored unless
+    /// [fetch_memory_budget][] is set.
+    ///
+    /// [fetch_memory_budget]: #method.fetch_memory_budget
+    pub fn grow_fetch_array_size(&mut self, b: bool) -> &mut StatementBuilder<'conn, 'sql> {
+        self.grow_fetch_array_size = b;
+        self
+    }
+
+    /// Makes the statement scrollable when `b` is true.
+    ///
+    /// A scrollable statement may be executed by [Statement.query_scrollable][]
+    /// to move the cursor forward and backward.
+    ///
+    /// [Statement.query_scrollable]: struct.Statement.html#method.query_scrollable
+    pub fn scrollable(&mut self, b: bool) -> &mut StatementBuilder<'conn, 'sql> {
+        self.scrollable = b;
+        self
+    }
+
+    /// Searches the statement cache by the tag.
+    ///
+    /// When a statement put into the cache with the tag is found,
+    /// it is reused and the SQL text is ignored. Otherwise, the statement
+    /// is prepared from the SQL text as usual. In both cases, the statement
+    /// is put into the cache with the tag when it is closed or dropped.
+    /// See also [Statement.close_with_tag][] and [Statement.found_in_cache][].
+    ///
+    /// [Statement.close_with_tag]: struct.Statement.html#method.close_with_tag
+    /// [Statement.found_in_cache]: struct.Statement.html#method.found_in_cache
+    pub fn tag<S>(&mut self, tag: S) -> &mut StatementBuilder<'conn, 'sql>
+    where
+        S: Into<String>,
+    {
+        self.tag = tag.into();
+        self
+    }
+
+    /// Excludes the statement from the statement cache when `b` is true.
+    ///
+    /// The statement is removed from the cache when it is closed or dropped
+    /// even though it has a [tag][] or is closed by [Statement.close_with_tag][].
+    /// It keeps statements executed only once from pushing frequently used
+    /// ones out of the cache.
+    ///
+    /// [tag]: #method.tag
+    /// [Statement.close_with_tag]: struct.Statement.html#method.close_with_tag
+    pub fn exclude_from_cache(&mut self, b: bool) -> &mut StatementBuilder<'conn, 'sql> {
+        self.exclude_from_cache = b;
+        self
+    }
+
+    /// Commits the transaction when the statement succeeds if `b` is true.
+    ///
+    /// This overrides [Connection.autocommit][] for the statement.
+    /// When this isn't specified, the statement follows the connection's
+    /// autocommit mode at the time of execution.
+    ///
+    /// [Connection.autocommit]: struct.Connection.html#method.autocommit
+    pub fn commit_on_success(&mut self, b: bool) -> &mut StatementBuilder<'conn, 'sql> {
+        self.commit_on_success = Some(b);
+        self
+    }
+
+    /// Sets the maximum time to execute the statement in milliseconds precision.
+    ///
+    /// The timeout is applied only to each round trip executing the statement.
+    /// When it expires, the execution is cancelled and an error is returned.
+    /// Fetches of query rows after the execution are not bounded by the
+    /// timeout.
+    ///
+    /// This requires Oracle client 18.1 or upper.
+    pub fn call_timeout(&mut self, timeout: Duration) -> &mut StatementBuilder<'conn, 'sql> {
+        self.call_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets a callback to determine the data type to define each column
+    /// of the query.
+    ///
+    /// The callback is called with column information when the query
+    /// is executed first. When it returns `Ok(Some(oratype))`, the column
+    /// is fetched as `oratype`. When it returns `Ok(None)`, the column is
+    /// fetched as usual. This overrides
+    /// [Connection.set_output_type_handler][] for the statement.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::*; use oracle::sql_type::OracleType; fn try_main() -> Result<()> {
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    /// // Fetch NUMBER columns as strings to keep exact decimal values.
+    /// let mut stmt = conn
+    ///     .statement("select empno, sal from emp")
+    ///     .output_type_handler(|info| match *info.oracle_type() {
+    ///         OracleType::Number(_, _) => Ok(Some(OracleType::Varchar2(40))),
+    ///         _ => Ok(None),
+    ///     })
+    ///     .build()?;
+    /// for row_result in stmt.query_as::<(String, String)>(&[])? {
+    ///     let (empno, sal) = row_result?;
+    ///     println!("{} {}", empno, sal);
+    /// }
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    ///
+    /// [Connection.set_output_type_handler]: struct.Connection.html#method.set_output_type_handler
+    pub fn output_type_handler<F>(&mut self, handler: F) -> &mut StatementBuilder<'conn, 'sql>
+    where
+        F: Fn(&ColumnInfo) -> Result<Option<OracleType>> + Send + Sync + 'static,
+    {
+        self.output_type_handler = Some(OutputTypeHandler::new(handler));
+        self
+    }
+
+    /// Fetches CLOB and BLOB columns inline as long string and long raw
+    /// values when `b` is true.
+    ///
+    /// Column values are usually fetched as LOB locators and their contents
+    /// are read by additional round trips when they are converted to
+    /// `String` or `Vec<u8>`. When this is set, the contents arrive along
+    /// with the fetched rows instead. It is suitable for small documents.
+    ///
+    /// The size of inline values is limited by [max_lob_size][] or
+    /// [Connection.set_max_lob_size][]. When a fetched value is larger than
+    /// the limit, the fetch returns `Error::OutOfRange` naming the column.
+    /// Note that the limit is checked after the fetch. Long values are
+    /// fetched into dynamically allocated buffers, so a value larger than the
+    /// limit is transferred and held in memory until the error is returned.
+    ///
+    /// NCLOB columns are still fetched as LOB locators because ODPI-C 3.2
+    /// used by this crate has no long type in the national character set.
+    /// Columns whose types are changed by an output type handler
+    /// are not affected.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::*; fn try_main() -> Result<()> {
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    /// let mut stmt = conn
+    ///     .statement("select id, doc from documents")
+    ///     .fetch_lobs_inline(true)
+    ///     .max_lob_size(64 * 1024)
+    ///     .build()?;
+    /// for row_result in stmt.query_as::<(i32, String)>(&[])? {
+    ///     let (id, doc) = row_result?;
+    ///     println!("{}: {}", id, doc);
+    /// }
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    ///
+    /// [max_lob_size]: #method.max_lob_size
+    /// [Connection.set_max_lob_size]: struct.Connection.html#method.set_max_lob_size
+    pub fn fetch_lobs_inline(&mut self, b: bool) -> &mut StatementBuilder<'conn, 'sql> {
+        self.fetch_lobs_inline = b;
+        self
+    }
+
+    /// Sets the maximum size in bytes of LOB values converted to `String`
+    /// or `Vec<u8>`.
+    ///
+    /// When a `CLOB`, `NCLOB` or `BLOB` column value is larger than the size,
+    /// the conversion returns `Error::OutOfRange` naming the column instead of
+    /// reading the whole value into memory. The size also limits values
+    /// fetched by [fetch_lobs_inline][]. This overrides
+    /// [Connection.set_max_lob_size][] for the statement.
+    ///
+    /// [fetch_lobs_inline]: #method.fetch_lobs_inline
+    /// [Connection.set_max_lob_size]: struct.Connection.html#method.set_max_lob_size
+    pub fn max_lob_size(&mut self, size: u64) -> &mut StatementBuilder<'conn, 'sql> {
+        self.max_lob_size = Some(size);
+        self
+    }
+
+    /// Prepares the statement.
+    pub fn build(&self) -> Result<Statement<'conn>> {
+        Statement::from_builder(self.conn, self)
+    }
+
+    /// Creates an [OwnedStatement][] which owns a reference to `conn`.
+    ///
+    /// `conn` must be the connection which [Connection.statement][] was
+    /// called on. Otherwise, `Error::InvalidOperation` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::*; use std::sync::Arc; fn try_main() -> Result<()> {
+    /// let conn = Arc::new(Connection::connect("scott", "tiger", "")?);
+    /// let mut stmt = conn
+    ///     .statement("select ename from emp where empno = :1")
+    ///     .fetch_array_size(1)
+    ///     .build_owned(&conn)?;
+    /// println!("{}", stmt.query_row_as::<String>(&[&7369])?);
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    ///
+    /// [OwnedStatement]: struct.OwnedStatement.html
+    /// [Connection.statement]: struct.Connection.html#method.statement
+    pub fn build_owned(&self, conn: &Arc<Connection>) -> Result<OwnedStatement> {
+        if !ptr::eq(self.conn, &**conn) {
+            return Err(Error::InvalidOperation(
+                "The connection isn't the one creating the statement builder.".to_string(),
+            ));
+        }
+        OwnedStatement::from_builder(conn, self)
+    }
+
+    /// Prepares and executes a select statement and returns a result set
+    /// containing [RowValue][]s.
+    ///
+    /// `params` is one of types implementing [BindParams][], such as
+    /// `&[&dyn ToSql]`, `&[(&str, &dyn ToSql)]`, tuples, `HashMap<&str, T>`
+    /// and structs deriving `BindParams`. Use `()` when the statement has no
+    /// bind variables. `T` is [Row][] or one of types implementing [RowValue][].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::*; fn try_main() -> Result<()> {
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    /// let sql = "select empno, ename from emp where deptno = :deptno";
+    /// for result in conn.statement(sql).query::<(i32, String), _>(&[("deptno", &10)])? {
+    ///     let (empno, ename) = result?;
+    ///     println!("empno: {}, ename: {}", empno, ename);
+    /// }
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    ///
+    /// [BindParams]: trait.BindParams.html
+    /// [Row]: struct.Row.html
+    /// [RowValue]: trait.RowValue.html
+    pub fn query<T, P>(&self, params: P) -> Result<ResultSet<'conn, T>>
+    where
+        T: RowValue,
+        P: BindParams,
+    {
+        let mut stmt = Box::new(self.build()?);
+        stmt.exec_params(&params, true, "query")?;
+        Ok(ResultSet::from_boxed_stmt(stmt))
+    }
+
+    /// Prepares and executes a select statement and returns the first row.
+    ///
+    /// It returns `Err(Error::NoDataFound)` when no rows are found.
+    /// The fetch array size is one unless it is specified explicitly.
+    /// See [query][] for `params` and `T`.
+    ///
+    /// [query]: #method.query
+    pub fn query_row<T, P>(&self, params: P) -> Result<T>
+    where
+        T: RowValue,
+        P: BindParams,
+    {
+        match self.query_opt_internal(params, "query_row")? {
+            Some(row) => Ok(row),
+            None => Err(Error::NoDataFound),
+        }
+    }
+
+    /// Prepares and executes a select statement and returns the first row
+    /// if it exists.
+    ///
+    /// It returns `Ok(None)` when no rows are found.
+    /// The fetch array size is one unless it is specified explicitly.
+    /// See [query][] for `params` and `T`.
+    ///
+    /// [query]: #method.query
+    pub fn query_opt<T, P>(&self, params: P) -> Result<Option<T>>
+    where
+        T: RowValue,
+        P: BindParams,
+    {
+        self.query_opt_internal(params, "query_opt")
+    }
+
+    fn query_opt_internal<T, P>(&self, params: P, method_name: &str) -> Result<Option<T>>
+    where
+        T: RowValue,
+        P: BindParams,
+    {
+        let mut builder = self.clone();
+        if builder.fetch_array_size.is_none() {
+            builder.fetch_array_size = Some(1);
+        }
+        let mut stmt = builder.build()?;
+        stmt.exec_params(&params, true, method_name)?;
+        let first = ResultSet::<T>::new(&stmt).next();
+        match first {
+            Some(Ok(row)) => Ok(Some(row)),
+            Some(Err(err)) => Err(err),
+            None => Ok(None),
+        }
+    }
+
+    /// Prepares and executes a non-select statement and returns the statement.
+    ///
+    /// The returned statement is used to get [row_count][] and [returned_values][].
+    /// See [query][] for `params`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::*; fn try_main() -> Result<()> {
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    /// let stmt = conn
+    ///     .statement("update emp set sal = sal * 1.1 where deptno = :1")
+    ///     .execute((10,))?;
+    /// println!("{} rows are updated.", stmt.row_count()?);
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    ///
+    /// [row_count]: struct.Statement.html#method.row_count
+    /// [returned_values]: struct.Statement.html#method.returned_values
+    /// [query]: #method.query
+    pub fn execute<P>(&self, params: P) -> Result<Statement<'conn>>
+    where
+        P: BindParams,
+    {
+        let mut stmt = self.build()?;
+        stmt.exec_params(&params, false, "execute")?;
+        Ok(stmt)
+    }
+}
+
 /// Statement
 pub struct Statement<'conn> {
     pub(crate) conn: &'conn Connection,
@@ -171,48 +661,103 @@ pub struct Statement<'conn> {
     shared_buffer_row_index: Rc<RefCell<u32>>,
     statement_type: StatementType,
     is_returning: bool,
+    scrollable: bool,
+    tag: String,
+    found_in_cache: bool,
     bind_count: usize,
     bind_names: Vec<String>,
     bind_values: Vec<SqlValue>,
-    fetch_array_size: u32,
+    fetch_array_size: Cell<u32>,
+    initial_fetch_array_size: u32,
+    fetch_memory_budget: Option<usize>,
+    grow_fetch_array_size: bool,
+    max_fetch_array_size: u32,
+    commit_on_success: Option<bool>,
+    call_timeout: Option<u32>,
+    output_type_handler: Option<OutputTypeHandler>,
+    fetch_lobs_inline: bool,
+    inline_lob_columns: Vec<usize>,
+    max_lob_size: Option<u64>,
 }
 
 impl<'conn> Statement<'conn> {
+    #[allow(deprecated)]
     pub(crate) fn new(
         conn: &'conn Connection,
         sql: &str,
         params: &[StmtParam],
     ) -> Result<Statement<'conn>> {
-        let sql = to_odpi_str(sql);
-        let mut fetch_array_size = DPI_DEFAULT_FETCH_ARRAY_SIZE;
-        let mut scrollable = 0;
-        let mut tag = new_odpi_str();
+        let mut builder = StatementBuilder::new(conn, sql);
+        Statement::apply_params(&mut builder, params);
+        builder.build()
+    }
+
+    #[allow(deprecated)]
+    fn apply_params(builder: &mut StatementBuilder, params: &[StmtParam]) {
         for param in params {
             match param {
                 &StmtParam::FetchArraySize(size) => {
-                    fetch_array_size = size;
+                    builder.fetch_array_size(size);
                 }
                 &StmtParam::Scrollable => {
-                    scrollable = 1;
+                    builder.scrollable(true);
                 }
                 &StmtParam::Tag(ref name) => {
-                    tag = to_odpi_str(name);
+                    builder.tag(name.as_str());
                 }
             }
         }
+    }
+
+    fn from_builder(
+        conn: &'conn Connection,
+        builder: &StatementBuilder<'_, '_>,
+    ) -> Result<Statement<'conn>> {
+        let call_timeout = match builder.call_timeout {
+            Some(timeout) => {
+                let msecs = timeout.as_secs() * 1000 + timeout.subsec_millis() as u64;
+                if msecs > u32::max_value() as u64 {
+                    return Err(Error::OutOfRange(format!(
+                        "Too long call timeout: {:?}",
+                        timeout
+                    )));
+                }
+                Some(msecs as u32)
+            }
+            None => None,
+        };
+        let sql = to_odpi_str(builder.sql);
+        let odpi_tag = to_odpi_str(&builder.tag);
+        let mut found_in_cache = 0;
+        chkerr!(
+            conn.ctxt,
+            dpi_ext_dpiConn_findStmtInCache(
+                conn.handle.raw(),
+                sql.ptr,
+                sql.len,
+                odpi_tag.ptr,
+                odpi_tag.len,
+                &mut found_in_cache
+            )
+        );
         let mut handle: *mut dpiStmt = ptr::null_mut();
         chkerr!(
             conn.ctxt,
             dpiConn_prepareStmt(
                 conn.handle.raw(),
-                scrollable,
+                if builder.scrollable { 1 } else { 0 },
                 sql.ptr,
                 sql.len,
-                tag.ptr,
-                tag.len,
+                odpi_tag.ptr,
+                odpi_tag.len,
                 &mut handle
             )
         );
+        if builder.exclude_from_cache {
+            chkerr!(conn.ctxt, dpi_ext_dpiStmt_deleteFromCache(handle), unsafe {
+                dpiStmt_release(handle);
+            });
+        }
         let mut info: dpiStmtInfo = Default::default();
         chkerr!(conn.ctxt, dpiStmt_getInfo(handle, &mut info), unsafe {
             dpiStmt_release(handle);
@@ -240,6 +785,9 @@ impl<'conn> Statement<'conn> {
                 bind_values.push(SqlValue::new(conn.ctxt));
             }
         };
+        let fetch_array_size = builder
+            .fetch_array_size
+            .unwrap_or(DPI_DEFAULT_FETCH_ARRAY_SIZE);
         Ok(Statement {
             conn: conn,
             handle: handle,
@@ -248,26 +796,125 @@ impl<'conn> Statement<'conn> {
             shared_buffer_row_index: Rc::new(RefCell::new(0)),
             statement_type: StatementType::from_enum(info.statementType),
             is_returning: info.isReturning != 0,
+            scrollable: builder.scrollable,
+            tag: builder.tag.clone(),
+            found_in_cache: found_in_cache != 0,
             bind_count: bind_count,
             bind_names: bind_names,
             bind_values: bind_values,
-            fetch_array_size: fetch_array_size,
+            fetch_array_size: Cell::new(fetch_array_size),
+            initial_fetch_array_size: fetch_array_size,
+            fetch_memory_budget: builder.fetch_memory_budget,
+            grow_fetch_array_size: builder.grow_fetch_array_size,
+            max_fetch_array_size: 0,
+            commit_on_success: builder.commit_on_success,
+            call_timeout: call_timeout,
+            output_type_handler: builder.output_type_handler.clone(),
+            fetch_lobs_inline: builder.fetch_lobs_inline,
+            inline_lob_columns: Vec::new(),
+            max_lob_size: builder.max_lob_size,
         })
     }
 
+    fn commit_on_success(&self) -> bool {
+        self.commit_on_success.unwrap_or(self.conn.autocommit)
+    }
+
+    // Calls `f` with the call timeout of the statement and then restores
+    // the connection's call timeout.
+    fn with_call_timeout<F>(&self, f: F) -> Result<()>
+    where
+        F: FnOnce() -> Result<()>,
+    {
+        let timeout = match self.call_timeout {
+            Some(timeout) => timeout,
+            None => return f(),
+        };
+        let conn_handle = self.conn.handle.raw();
+        let mut saved_timeout = 0;
+        chkerr!(
+            self.conn.ctxt,
+            dpiConn_getCallTimeout(conn_handle, &mut saved_timeout)
+        );
+        chkerr!(self.conn.ctxt, dpiConn_setCallTimeout(conn_handle, timeout));
+        let result = f();
+        if unsafe { dpiConn_setCallTimeout(conn_handle, saved_timeout) } != DPI_SUCCESS as i32
+            && result.is_ok()
+        {
+            return Err(error_from_context(self.conn.ctxt));
+        }
+        result
+    }
+
     /// Closes the statement before the end of lifetime.
+    ///
+    /// When the statement is prepared with [StatementBuilder.tag][], it is put
+    /// into the statement cache with the tag.
+    ///
+    /// [StatementBuilder.tag]: struct.StatementBuilder.html#method.tag
     pub fn close(&mut self) -> Result<()> {
-        self.close_internal("")
+        let tag = self.tag.clone();
+        self.close_internal(&tag)
+    }
+
+    /// Closes the statement and puts it into the statement cache with the tag.
+    ///
+    /// The statement can be reused by preparing a statement with
+    /// [StatementBuilder.tag][] and the same tag while it is in the cache.
+    /// The number of statements in the cache is limited by
+    /// [Connection.stmt_cache_size][]. The tag is ignored when the statement
+    /// is prepared with [StatementBuilder.exclude_from_cache][].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::*; fn try_main() -> Result<()> {
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    /// let sql = "select ename from emp where empno = :1";
+    /// let mut stmt = conn.prepare(sql, &[])?;
+    /// let ename = stmt.query_row_as::<String>(&[&7369])?;
+    /// stmt.close_with_tag("find_ename")?;
+    ///
+    /// // The cached statement is reused.
+    /// let mut stmt = conn.statement(sql).tag("find_ename").build()?;
+    /// let ename = stmt.query_row_as::<String>(&[&7499])?;
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    ///
+    /// [StatementBuilder.tag]: struct.StatementBuilder.html#method.tag
+    /// [Connection.stmt_cache_size]: struct.Connection.html#method.stmt_cache_size
+    /// [StatementBuilder.exclude_from_cache]: struct.StatementBuilder.html#method.exclude_from_cache
+    pub fn close_with_tag(&mut self, tag: &str) -> Result<()> {
+        self.close_internal(tag)
     }
 
     fn close_internal(&mut self, tag: &str) -> Result<()> {
         let tag = to_odpi_str(tag);
 
         chkerr!(self.conn.ctxt, dpiStmt_close(self.handle, tag.ptr, tag.len));
+        unsafe { dpiStmt_release(self.handle) };
         self.handle = ptr::null_mut();
         Ok(())
     }
 
+    /// Returns the tag specified by [StatementBuilder.tag][].
+    ///
+    /// [StatementBuilder.tag]: struct.StatementBuilder.html#method.tag
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// Returns `true` when the statement was found in the statement cache
+    /// when it was prepared.
+    ///
+    /// It is always `false` while the statement cache is disabled by
+    /// [Connection.set_stmt_cache_size][] with zero.
+    ///
+    /// [Connection.set_stmt_cache_size]: struct.Connection.html#method.set_stmt_cache_size
+    pub fn found_in_cache(&self) -> bool {
+        self.found_in_cache
+    }
+
     /// Executes the prepared statement and returns a result set containing [Row][]s.
     ///
     /// See [Query Methods][].
@@ -321,27 +968,94 @@ impl<'conn> Statement<'conn> {
         Ok(ResultSet::new(self))
     }
 
-    /// Gets one row from the prepared statement using positoinal bind parameters.
+    /// Executes the prepared statement and returns a scrollable result set
+    /// containing [RowValue][]s.
     ///
-    /// See [Query Methods][].
+    /// The statement must be prepared with [StatementBuilder.scrollable][].
     ///
-    /// [Query Methods]: https://github.com/kubo/rust-oracle/blob/master/docs/query-methods.md
-    pub fn query_row(&mut self, params: &[&dyn ToSql]) -> Result<Row> {
-        let mut rows = self.query(params)?;
-        rows.next().unwrap_or(Err(Error::NoDataFound))
-    }
-
-    /// Gets one row from the prepared statement using named bind parameters.
+    /// # Examples
     ///
-    /// See [Query Methods][].
+    /// ```no_run
+    /// # use oracle::*; fn try_main() -> Result<()> {
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    /// let sql = "select ename from emp order by empno";
+    /// let mut stmt = conn.statement(sql).scrollable(true).build()?;
+    /// let mut rows = stmt.query_scrollable::<String>(&[])?;
     ///
-    /// [Query Methods]: https://github.com/kubo/rust-oracle/blob/master/docs/query-methods.md
-    pub fn query_row_named(&mut self, params: &[(&str, &dyn ToSql)]) -> Result<Row> {
-        let mut rows = self.query_named(params)?;
-        rows.next().unwrap_or(Err(Error::NoDataFound))
+    /// // the last row
+    /// println!("{:?}", rows.scroll_last()?);
+    /// // the 10th row
+    /// println!("{:?}", rows.scroll_absolute(10)?);
+    /// // the 9th row
+    /// println!("{:?}", rows.scroll_prior()?);
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    ///
+    /// [RowValue]: struct.RowValue.html
+    /// [StatementBuilder.scrollable]: struct.StatementBuilder.html#method.scrollable
+    pub fn query_scrollable<'a, T>(
+        &'a mut self,
+        params: &[&dyn ToSql],
+    ) -> Result<ScrollableResultSet<'a, T>>
+    where
+        T: RowValue,
+    {
+        self.check_scrollable("query_scrollable")?;
+        self.exec(params, true, "query_scrollable")?;
+        Ok(ScrollableResultSet::new(ResultSet::new(self)))
     }
 
-    /// Gets one row from the prepared statement as specified type using positoinal bind parameters.
+    /// Executes the prepared statement using named parameters and returns
+    /// a scrollable result set containing [RowValue][]s.
+    ///
+    /// The statement must be prepared with [StatementBuilder.scrollable][].
+    ///
+    /// [RowValue]: struct.RowValue.html
+    /// [StatementBuilder.scrollable]: struct.StatementBuilder.html#method.scrollable
+    pub fn query_scrollable_named<'a, T>(
+        &'a mut self,
+        params: &[(&str, &dyn ToSql)],
+    ) -> Result<ScrollableResultSet<'a, T>>
+    where
+        T: RowValue,
+    {
+        self.check_scrollable("query_scrollable_named")?;
+        self.exec_named(params, true, "query_scrollable_named")?;
+        Ok(ScrollableResultSet::new(ResultSet::new(self)))
+    }
+
+    fn check_scrollable(&self, method_name: &str) -> Result<()> {
+        if self.scrollable {
+            Ok(())
+        } else {
+            Err(Error::InvalidOperation(format!(
+                "Could not use the `{}` method for statements prepared without StatementBuilder.scrollable(true)",
+                method_name
+            )))
+        }
+    }
+
+    /// Gets one row from the prepared statement using positoinal bind parameters.
+    ///
+    /// See [Query Methods][].
+    ///
+    /// [Query Methods]: https://github.com/kubo/rust-oracle/blob/master/docs/query-methods.md
+    pub fn query_row(&mut self, params: &[&dyn ToSql]) -> Result<Row> {
+        let mut rows = self.query(params)?;
+        rows.next().unwrap_or(Err(Error::NoDataFound))
+    }
+
+    /// Gets one row from the prepared statement using named bind parameters.
+    ///
+    /// See [Query Methods][].
+    ///
+    /// [Query Methods]: https://github.com/kubo/rust-oracle/blob/master/docs/query-methods.md
+    pub fn query_row_named(&mut self, params: &[(&str, &dyn ToSql)]) -> Result<Row> {
+        let mut rows = self.query_named(params)?;
+        rows.next().unwrap_or(Err(Error::NoDataFound))
+    }
+
+    /// Gets one row from the prepared statement as specified type using positoinal bind parameters.
     ///
     /// See [Query Methods][].
     ///
@@ -464,20 +1178,170 @@ impl<'conn> Statement<'conn> {
         self.exec_common()
     }
 
-    fn exec_common(&mut self) -> Result<()> {
+    /// Gets column information of the select statement without executing it.
+    ///
+    /// This sends the statement to the server in describe-only mode. No rows
+    /// are fetched and bind values aren't required.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::*; fn try_main() -> Result<()> {
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    /// let mut stmt = conn.prepare("select * from emp where empno = :1", &[])?;
+    /// for info in stmt.describe()? {
+    ///     println!("{}", info);
+    /// }
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    pub fn describe(&mut self) -> Result<&[ColumnInfo]> {
+        self.check_stmt_type(true, "describe")?;
+        if self.column_info.is_empty() {
+            let mut num_query_columns = 0;
+            chkerr!(
+                self.conn.ctxt,
+                dpiStmt_execute(
+                    self.handle,
+                    DPI_MODE_EXEC_DESCRIBE_ONLY,
+                    &mut num_query_columns
+                )
+            );
+            let num_cols = num_query_columns as usize;
+            let mut column_info = Vec::with_capacity(num_cols);
+            for i in 0..num_cols {
+                column_info.push(ColumnInfo::new(self, i)?);
+            }
+            self.column_info = column_info;
+        }
+        Ok(&self.column_info)
+    }
+
+    pub(crate) fn parse(&mut self) -> Result<()> {
+        if self.is_ddl() {
+            return Err(Error::InvalidOperation(
+                "Could not use the `parse` method for DDL statements".into(),
+            ));
+        }
         let mut num_query_columns = 0;
-        let mut exec_mode = DPI_MODE_EXEC_DEFAULT;
-        if self.conn.autocommit {
+        chkerr!(
+            self.conn.ctxt,
+            dpiStmt_execute(
+                self.handle,
+                DPI_MODE_EXEC_PARSE_ONLY,
+                &mut num_query_columns
+            )
+        );
+        Ok(())
+    }
+
+    pub(crate) fn exec_params<P>(
+        &mut self,
+        params: &P,
+        must_be_query: bool,
+        method_name: &str,
+    ) -> Result<()>
+    where
+        P: BindParams + ?Sized,
+    {
+        self.check_stmt_type(must_be_query, method_name)?;
+        params.bind(self)?;
+        self.exec_common()
+    }
+
+    /// Executes the statement with array DML in a single round trip.
+    ///
+    /// Bind values are created by `oratypes` with `num_rows` elements.
+    /// `set_value` is called with a bind value, its zero-based position and
+    /// a row index to set the value of the row.
+    /// It returns the number of affected rows and batch errors, whose
+    /// offsets are row indexes.
+    pub(crate) fn execute_many_internal<F>(
+        &mut self,
+        oratypes: &[OracleType],
+        num_rows: u32,
+        mut set_value: F,
+    ) -> Result<(u64, Vec<DbError>)>
+    where
+        F: FnMut(&mut SqlValue, usize, usize) -> Result<()>,
+    {
+        for (pos, oratype) in oratypes.iter().enumerate() {
+            if self.bind_values[pos].init_handle(&self.conn.handle, oratype, num_rows)? {
+                chkerr!(
+                    self.conn.ctxt,
+                    (pos + 1).bind(self.handle, self.bind_values[pos].handle)
+                );
+            }
+            let val = &mut self.bind_values[pos];
+            let result = (0..(num_rows as usize)).try_for_each(|row| {
+                val.buffer_row_index = BufferRowIndex::Owned(row as u32);
+                set_value(val, pos, row)
+            });
+            val.buffer_row_index = BufferRowIndex::Owned(0);
+            result?;
+        }
+        let mut exec_mode = DPI_MODE_EXEC_BATCH_ERRORS | DPI_MODE_EXEC_ARRAY_DML_ROWCOUNTS;
+        if self.commit_on_success() {
             exec_mode |= DPI_MODE_EXEC_COMMIT_ON_SUCCESS;
         }
+        let ctxt = self.conn.ctxt;
+        let handle = self.handle;
+        self.with_call_timeout(|| {
+            chkerr!(ctxt, dpiStmt_executeMany(handle, exec_mode, num_rows));
+            Ok(())
+        })?;
+        let mut num_errors = 0;
+        chkerr!(
+            self.conn.ctxt,
+            dpiStmt_getBatchErrorCount(self.handle, &mut num_errors)
+        );
+        let mut batch_errors = Vec::with_capacity(num_errors as usize);
+        if num_errors > 0 {
+            let mut errors = vec![dpiErrorInfo::default(); num_errors as usize];
+            chkerr!(
+                self.conn.ctxt,
+                dpiStmt_getBatchErrors(self.handle, num_errors, errors.as_mut_ptr())
+            );
+            for err in &errors {
+                match error_from_dpi_error(err) {
+                    Error::OciError(dberr) | Error::DpiError(dberr) => batch_errors.push(dberr),
+                    _ => (),
+                }
+            }
+        }
+        let mut num_row_counts = 0;
+        let mut row_counts = ptr::null_mut();
         chkerr!(
             self.conn.ctxt,
-            dpiStmt_setFetchArraySize(self.handle, self.fetch_array_size)
+            dpiStmt_getRowCounts(self.handle, &mut num_row_counts, &mut row_counts)
         );
+        let row_count: u64 = unsafe { slice::from_raw_parts(row_counts, num_row_counts as usize) }
+            .iter()
+            .sum();
+        Ok((row_count, batch_errors))
+    }
+
+    fn exec_common(&mut self) -> Result<()> {
+        let mut num_query_columns = 0;
+        let mut exec_mode = DPI_MODE_EXEC_DEFAULT;
+        if self.commit_on_success() {
+            exec_mode |= DPI_MODE_EXEC_COMMIT_ON_SUCCESS;
+        }
+        // The array size grown while fetching the previous result set
+        // starts over from the initial size.
+        self.fetch_array_size.set(self.initial_fetch_array_size);
         chkerr!(
             self.conn.ctxt,
-            dpiStmt_execute(self.handle, exec_mode, &mut num_query_columns)
+            dpiStmt_setFetchArraySize(self.handle, self.fetch_array_size.get())
         );
+        let ctxt = self.conn.ctxt;
+        let handle = self.handle;
+        self.with_call_timeout(|| {
+            chkerr!(
+                ctxt,
+                dpiStmt_execute(handle, exec_mode, &mut num_query_columns)
+            );
+            Ok(())
+        })?;
         if self.is_ddl() {
             let mut fncode = 0;
             chkerr!(
@@ -496,6 +1360,19 @@ impl<'conn> Statement<'conn> {
                 let num_cols = num_query_columns as usize;
                 let mut column_names = Vec::with_capacity(num_cols);
                 let mut column_values = Vec::with_capacity(num_cols);
+                let handler = self
+                    .output_type_handler
+                    .as_ref()
+                    .or(self.conn.output_type_handler.as_ref())
+                    .cloned();
+                let max_lob_size = self.max_lob_size.or(self.conn.max_lob_size);
+                // The array size is determined after all define types are known
+                // when the memory budget is set.
+                let array_size = if self.fetch_memory_budget.is_some() {
+                    1
+                } else {
+                    self.fetch_array_size.get()
+                };
                 self.column_info = Vec::with_capacity(num_cols);
 
                 for i in 0..num_cols {
@@ -507,24 +1384,37 @@ impl<'conn> Statement<'conn> {
                     let mut val = SqlValue::new(self.conn.ctxt);
                     val.buffer_row_index =
                         BufferRowIndex::Shared(self.shared_buffer_row_index.clone());
-                    let oratype = self.column_info[i].oracle_type();
-                    let oratype_i64 = OracleType::Int64;
-                    let oratype = match *oratype {
-                        // When the column type is number whose prec is less than 18
-                        // and the scale is zero, define it as int64.
-                        OracleType::Number(prec, 0)
-                            if 0 < prec && prec < DPI_MAX_INT64_PRECISION as u8 =>
-                        {
-                            &oratype_i64
-                        }
-                        _ => oratype,
+                    let oratype = match handler {
+                        Some(ref handler) => handler.call(&self.column_info[i])?,
+                        None => None,
+                    };
+                    let oratype = match oratype {
+                        Some(oratype) => oratype,
+                        None => match self.column_info[i].inline_lob_type() {
+                            Some(oratype) if self.fetch_lobs_inline => {
+                                self.inline_lob_columns.push(i);
+                                oratype
+                            }
+                            _ => self.column_info[i].define_type(),
+                        },
                     };
-                    val.init_handle(&self.conn.handle, oratype, self.fetch_array_size)?;
+                    val.init_handle(&self.conn.handle, &oratype, array_size)?;
+                    if let Some(max_size) = max_lob_size {
+                        val.lob_size_limit = Some(LobSizeLimit {
+                            max_size: max_size,
+                            column_name: self.column_info[i].name.clone(),
+                        });
+                    }
+                    column_values.push(val);
+                }
+                if let Some(budget) = self.fetch_memory_budget {
+                    self.set_fetch_array_size_by_budget(budget, &mut column_values)?;
+                }
+                for (i, val) in column_values.iter().enumerate() {
                     chkerr!(
                         self.conn.ctxt,
                         dpiStmt_define(self.handle, (i + 1) as u32, val.handle)
                     );
-                    column_values.push(val);
                 }
                 self.row = Some(Row::new(self.conn, column_names, column_values)?);
             }
@@ -537,6 +1427,66 @@ impl<'conn> Statement<'conn> {
         Ok(())
     }
 
+    // Sets the fetch array size to the number of rows whose define buffers
+    // fit in `budget` bytes and reallocates the buffers for the size.
+    fn set_fetch_array_size_by_budget(
+        &mut self,
+        budget: usize,
+        column_values: &mut [SqlValue],
+    ) -> Result<()> {
+        let mut row_size = 0;
+        for val in column_values.iter() {
+            let mut size_in_bytes = 0;
+            chkerr!(
+                self.conn.ctxt,
+                dpiVar_getSizeInBytes(val.handle, &mut size_in_bytes)
+            );
+            row_size += size_in_bytes as usize + mem::size_of::<dpiData>();
+        }
+        let max_size = cmp::max(budget / cmp::max(row_size, 1), 1);
+        let max_size = cmp::min(max_size, u32::max_value() as usize) as u32;
+        for val in column_values.iter_mut() {
+            let oratype = val.oracle_type()?.clone();
+            val.init_handle(&self.conn.handle, &oratype, max_size)?;
+        }
+        let size = if self.grow_fetch_array_size {
+            cmp::min(DPI_DEFAULT_FETCH_ARRAY_SIZE, max_size)
+        } else {
+            max_size
+        };
+        chkerr!(self.conn.ctxt, dpiStmt_setFetchArraySize(self.handle, size));
+        self.fetch_array_size.set(size);
+        self.initial_fetch_array_size = size;
+        self.max_fetch_array_size = max_size;
+        Ok(())
+    }
+
+    // Doubles the fetch array size for the next round trip up to the size
+    // determined by the memory budget when the array size grows.
+    fn grow_fetch_array_size(&self) -> Result<()> {
+        let size = self.fetch_array_size.get();
+        if self.grow_fetch_array_size && !self.scrollable && size < self.max_fetch_array_size {
+            let new_size = cmp::min(size.saturating_mul(2), self.max_fetch_array_size);
+            chkerr!(
+                self.conn.ctxt,
+                dpiStmt_setFetchArraySize(self.handle, new_size)
+            );
+            self.fetch_array_size.set(new_size);
+        }
+        Ok(())
+    }
+
+    /// Returns the fetch array size used by the next round trip.
+    ///
+    /// This is the value set by [StatementBuilder.fetch_array_size][]
+    /// or determined by [StatementBuilder.fetch_memory_budget][].
+    ///
+    /// [StatementBuilder.fetch_array_size]: struct.StatementBuilder.html#method.fetch_array_size
+    /// [StatementBuilder.fetch_memory_budget]: struct.StatementBuilder.html#method.fetch_memory_budget
+    pub fn fetch_array_size(&self) -> u32 {
+        self.fetch_array_size.get()
+    }
+
     /// Returns the number of bind variables in the statement.
     ///
     /// In SQL statements this is the total number of bind variables whereas in
@@ -722,6 +1672,15 @@ impl<'conn> Statement<'conn> {
         if unsafe { dpiStmt_fetch(self.handle, &mut found, &mut buffer_row_index) } == 0 {
             if found != 0 {
                 *self.shared_buffer_row_index.borrow_mut() = buffer_row_index;
+                if let Err(err) = self.check_inline_lob_sizes(buffer_row_index, 1) {
+                    return Some(Err(err));
+                }
+                // The buffer row index is zero just after a round trip.
+                if buffer_row_index == 0 {
+                    if let Err(err) = self.grow_fetch_array_size() {
+                        return Some(Err(err));
+                    }
+                }
                 // if self.row.is_none(), dpiStmt_fetch() returns non-zero.
                 Some(Ok(self.row.as_ref().unwrap()))
             } else {
@@ -732,6 +1691,66 @@ impl<'conn> Statement<'conn> {
         }
     }
 
+    /// Moves the cursor of a scrollable statement. The next fetch returns
+    /// the row at the new position.
+    ///
+    /// It returns `false` when the new position is out of the result set.
+    pub(crate) fn scroll(&self, mode: dpiFetchMode, offset: i32) -> Result<bool> {
+        if unsafe { dpiStmt_scroll(self.handle, mode, offset, 0) } == DPI_SUCCESS as i32 {
+            return Ok(true);
+        }
+        match crate::error::error_from_context(self.conn.ctxt) {
+            Error::DpiError(ref err) if dpi_error_code(err) == Some(DPI_ERR_SCROLL_OUT_OF_RS) => {
+                Ok(false)
+            }
+            err => Err(err),
+        }
+    }
+
+    /// Fetches rows into the define buffers at a time.
+    ///
+    /// It returns the buffer row index of the first fetched row and the
+    /// number of fetched rows, which is zero when no more rows are available.
+    pub(crate) fn fetch_rows(&self) -> Result<(u32, u32)> {
+        let mut buffer_row_index = 0;
+        let mut num_rows = 0;
+        let mut more_rows = 0;
+        chkerr!(
+            self.conn.ctxt,
+            dpiStmt_fetchRows(
+                self.handle,
+                self.fetch_array_size.get(),
+                &mut buffer_row_index,
+                &mut num_rows,
+                &mut more_rows
+            )
+        );
+        self.check_inline_lob_sizes(buffer_row_index, num_rows)?;
+        if more_rows != 0 && buffer_row_index == 0 {
+            self.grow_fetch_array_size()?;
+        }
+        Ok((buffer_row_index, num_rows))
+    }
+
+    // Checks that inline LOB values in the define buffers from
+    // `buffer_row_index` to `buffer_row_index + num_rows` don't exceed
+    // the maximum LOB size.
+    fn check_inline_lob_sizes(&self, buffer_row_index: u32, num_rows: u32) -> Result<()> {
+        if self.inline_lob_columns.is_empty() {
+            return Ok(());
+        }
+        let row = self.row.as_ref().unwrap();
+        for &pos in &self.inline_lob_columns {
+            let val = &row.column_values[pos];
+            for idx in buffer_row_index..(buffer_row_index + num_rows) {
+                if let Some(len) = val.bytes_len_at(idx) {
+                    val.check_lob_size(len as u64)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Returns the number of rows fetched when the SQL statement is a query.
     /// Otherwise, the number of rows affected.
     pub fn row_count(&self) -> Result<u64> {
@@ -785,6 +1804,14 @@ impl<'conn> Statement<'conn> {
 
 impl<'conn> Drop for Statement<'conn> {
     fn drop(&mut self) {
+        if self.handle.is_null() {
+            return;
+        }
+        if !self.tag.is_empty() {
+            // put the statement into the statement cache with the tag.
+            let tag = to_odpi_str(&self.tag);
+            unsafe { dpiStmt_close(self.handle, tag.ptr, tag.len) };
+        }
         unsafe { dpiStmt_release(self.handle) };
     }
 }
@@ -812,7 +1839,7 @@ impl<'conn> fmt::Debug for Statement<'conn> {
                     if colinfo.nullable() { "" } else { " NOT NULL" }
                 )?;
             }
-            write!(f, "], fetch_array_size: {}", self.fetch_array_size)?;
+            write!(f, "], fetch_array_size: {}", self.fetch_array_size.get())?;
         }
         if self.bind_count != 0 {
             write!(
@@ -824,10 +1851,337 @@ impl<'conn> fmt::Debug for Statement<'conn> {
         if self.is_returning {
             write!(f, ", is_returning: true")?;
         }
+        if !self.tag.is_empty() {
+            write!(f, ", tag: {:?}", self.tag)?;
+        }
         write!(f, " }}")
     }
 }
 
+/// Statement which owns its connection
+///
+/// Unlike [Statement][], this has no lifetime parameter because it keeps
+/// the connection alive by `Arc<Connection>`. It can be stored in a struct
+/// along with the connection to reuse the prepared statement.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use oracle::*; use std::sync::Arc;
+/// struct EmpRepository {
+///     find_ename: OwnedStatement,
+/// }
+///
+/// impl EmpRepository {
+///     fn new(conn: &Arc<Connection>) -> Result<EmpRepository> {
+///         Ok(EmpRepository {
+///             find_ename: OwnedStatement::new(conn, "select ename from emp where empno = :1", &[])?,
+///         })
+///     }
+///
+///     fn find_ename(&mut self, empno: i32) -> Result<String> {
+///         self.find_ename.query_row_as(&[&empno])
+///     }
+/// }
+///
+/// # fn try_main() -> Result<()> {
+/// let conn = Arc::new(Connection::connect("scott", "tiger", "")?);
+/// let mut repo = EmpRepository::new(&conn)?;
+/// println!("{}", repo.find_ename(7369)?);
+/// println!("{}", repo.find_ename(7499)?);
+/// # Ok(())} fn main() { try_main().unwrap(); }
+/// ```
+///
+/// [Statement]: struct.Statement.html
+pub struct OwnedStatement {
+    // `stmt` refers to the connection in `conn`. It must be dropped before `conn`.
+    stmt: Statement<'static>,
+    conn: Arc<Connection>,
+}
+
+impl OwnedStatement {
+    /// Prepares a statement which owns a reference to the connection.
+    ///
+    /// See [Connection.prepare][] for `params`. Use
+    /// [StatementBuilder.build_owned][] to prepare it with a builder instead.
+    ///
+    /// [Connection.prepare]: struct.Connection.html#method.prepare
+    /// [StatementBuilder.build_owned]: struct.StatementBuilder.html#method.build_owned
+    #[allow(deprecated)]
+    pub fn new(conn: &Arc<Connection>, sql: &str, params: &[StmtParam]) -> Result<OwnedStatement> {
+        let mut builder = StatementBuilder::new(conn, sql);
+        Statement::apply_params(&mut builder, params);
+        OwnedStatement::from_builder(conn, &builder)
+    }
+
+    fn from_builder(conn: &Arc<Connection>, builder: &StatementBuilder) -> Result<OwnedStatement> {
+        // The connection outlives the statement because `conn` is kept in
+        // the returned value and the statement is never moved out of it
+        // without the connection.
+        let conn_ref: &'static Connection = unsafe { &*(&**conn as *const Connection) };
+        Ok(OwnedStatement {
+            stmt: Statement::from_builder(conn_ref, builder)?,
+            conn: conn.clone(),
+        })
+    }
+
+    /// Returns the connection which the statement belongs to.
+    pub fn connection(&self) -> &Arc<Connection> {
+        &self.conn
+    }
+
+    /// See [Statement.close][].
+    ///
+    /// [Statement.close]: struct.Statement.html#method.close
+    pub fn close(&mut self) -> Result<()> {
+        self.stmt.close()
+    }
+
+    /// See [Statement.close_with_tag][].
+    ///
+    /// [Statement.close_with_tag]: struct.Statement.html#method.close_with_tag
+    pub fn close_with_tag(&mut self, tag: &str) -> Result<()> {
+        self.stmt.close_with_tag(tag)
+    }
+
+    /// See [Statement.tag][].
+    ///
+    /// [Statement.tag]: struct.Statement.html#method.tag
+    pub fn tag(&self) -> &str {
+        self.stmt.tag()
+    }
+
+    /// See [Statement.found_in_cache][].
+    ///
+    /// [Statement.found_in_cache]: struct.Statement.html#method.found_in_cache
+    pub fn found_in_cache(&self) -> bool {
+        self.stmt.found_in_cache()
+    }
+
+    /// See [Statement.query][].
+    ///
+    /// [Statement.query]: struct.Statement.html#method.query
+    pub fn query(&mut self, params: &[&dyn ToSql]) -> Result<ResultSet<Row>> {
+        self.stmt.query(params)
+    }
+
+    /// See [Statement.query_named][].
+    ///
+    /// [Statement.query_named]: struct.Statement.html#method.query_named
+    pub fn query_named(&mut self, params: &[(&str, &dyn ToSql)]) -> Result<ResultSet<Row>> {
+        self.stmt.query_named(params)
+    }
+
+    /// See [Statement.query_as][].
+    ///
+    /// [Statement.query_as]: struct.Statement.html#method.query_as
+    pub fn query_as<T>(&mut self, params: &[&dyn ToSql]) -> Result<ResultSet<T>>
+    where
+        T: RowValue,
+    {
+        self.stmt.query_as(params)
+    }
+
+    /// See [Statement.query_as_named][].
+    ///
+    /// [Statement.query_as_named]: struct.Statement.html#method.query_as_named
+    pub fn query_as_named<T>(&mut self, params: &[(&str, &dyn ToSql)]) -> Result<ResultSet<T>>
+    where
+        T: RowValue,
+    {
+        self.stmt.query_as_named(params)
+    }
+
+    /// Executes the query and converts the statement into a result set
+    /// which owns the statement and the connection.
+    ///
+    /// Use `Row` as `T` to get rows as [Row][]s.
+    ///
+    /// [Row]: struct.Row.html
+    pub fn into_query_as<T>(mut self, params: &[&dyn ToSql]) -> Result<OwnedResultSet<T>>
+    where
+        T: RowValue,
+    {
+        self.stmt.exec(params, true, "into_query_as")?;
+        Ok(self.into_result_set())
+    }
+
+    /// Executes the query using named parameters and converts the statement
+    /// into a result set which owns the statement and the connection.
+    ///
+    /// Use `Row` as `T` to get rows as [Row][]s.
+    ///
+    /// [Row]: struct.Row.html
+    pub fn into_query_as_named<T>(
+        mut self,
+        params: &[(&str, &dyn ToSql)],
+    ) -> Result<OwnedResultSet<T>>
+    where
+        T: RowValue,
+    {
+        self.stmt.exec_named(params, true, "into_query_as_named")?;
+        Ok(self.into_result_set())
+    }
+
+    fn into_result_set<T>(self) -> OwnedResultSet<T>
+    where
+        T: RowValue,
+    {
+        OwnedResultSet::new(ResultSet::from_boxed_stmt(Box::new(self.stmt)), self.conn)
+    }
+
+    /// See [Statement.query_row][].
+    ///
+    /// [Statement.query_row]: struct.Statement.html#method.query_row
+    pub fn query_row(&mut self, params: &[&dyn ToSql]) -> Result<Row> {
+        self.stmt.query_row(params)
+    }
+
+    /// See [Statement.query_row_named][].
+    ///
+    /// [Statement.query_row_named]: struct.Statement.html#method.query_row_named
+    pub fn query_row_named(&mut self, params: &[(&str, &dyn ToSql)]) -> Result<Row> {
+        self.stmt.query_row_named(params)
+    }
+
+    /// See [Statement.query_row_as][].
+    ///
+    /// [Statement.query_row_as]: struct.Statement.html#method.query_row_as
+    pub fn query_row_as<T>(&mut self, params: &[&dyn ToSql]) -> Result<T>
+    where
+        T: RowValue,
+    {
+        self.stmt.query_row_as(params)
+    }
+
+    /// See [Statement.query_row_as_named][].
+    ///
+    /// [Statement.query_row_as_named]: struct.Statement.html#method.query_row_as_named
+    pub fn query_row_as_named<T>(&mut self, params: &[(&str, &dyn ToSql)]) -> Result<T>
+    where
+        T: RowValue,
+    {
+        self.stmt.query_row_as_named(params)
+    }
+
+    /// See [Statement.execute][].
+    ///
+    /// [Statement.execute]: struct.Statement.html#method.execute
+    pub fn execute(&mut self, params: &[&dyn ToSql]) -> Result<()> {
+        self.stmt.execute(params)
+    }
+
+    /// See [Statement.execute_named][].
+    ///
+    /// [Statement.execute_named]: struct.Statement.html#method.execute_named
+    pub fn execute_named(&mut self, params: &[(&str, &dyn ToSql)]) -> Result<()> {
+        self.stmt.execute_named(params)
+    }
+
+    /// See [Statement.describe][].
+    ///
+    /// [Statement.describe]: struct.Statement.html#method.describe
+    pub fn describe(&mut self) -> Result<&[ColumnInfo]> {
+        self.stmt.describe()
+    }
+
+    /// See [Statement.bind_count][].
+    ///
+    /// [Statement.bind_count]: struct.Statement.html#method.bind_count
+    pub fn bind_count(&self) -> usize {
+        self.stmt.bind_count()
+    }
+
+    /// See [Statement.bind_names][].
+    ///
+    /// [Statement.bind_names]: struct.Statement.html#method.bind_names
+    pub fn bind_names(&self) -> Vec<&str> {
+        self.stmt.bind_names()
+    }
+
+    /// See [Statement.bind][].
+    ///
+    /// [Statement.bind]: struct.Statement.html#method.bind
+    pub fn bind<I>(&mut self, bindidx: I, value: &dyn ToSql) -> Result<()>
+    where
+        I: BindIndex,
+    {
+        self.stmt.bind(bindidx, value)
+    }
+
+    /// See [Statement.bind_value][].
+    ///
+    /// [Statement.bind_value]: struct.Statement.html#method.bind_value
+    pub fn bind_value<I, T>(&self, bindidx: I) -> Result<T>
+    where
+        I: BindIndex,
+        T: FromSql,
+    {
+        self.stmt.bind_value(bindidx)
+    }
+
+    /// See [Statement.returned_values][].
+    ///
+    /// [Statement.returned_values]: struct.Statement.html#method.returned_values
+    pub fn returned_values<I, T>(&self, bindidx: I) -> Result<Vec<T>>
+    where
+        I: BindIndex,
+        T: FromSql,
+    {
+        self.stmt.returned_values(bindidx)
+    }
+
+    /// See [Statement.row_count][].
+    ///
+    /// [Statement.row_count]: struct.Statement.html#method.row_count
+    pub fn row_count(&self) -> Result<u64> {
+        self.stmt.row_count()
+    }
+
+    /// See [Statement.fetch_array_size][].
+    ///
+    /// [Statement.fetch_array_size]: struct.Statement.html#method.fetch_array_size
+    pub fn fetch_array_size(&self) -> u32 {
+        self.stmt.fetch_array_size()
+    }
+
+    /// Returns statement type
+    pub fn statement_type(&self) -> StatementType {
+        self.stmt.statement_type()
+    }
+
+    /// Returns true when the SQL statement is a query.
+    pub fn is_query(&self) -> bool {
+        self.stmt.is_query()
+    }
+
+    /// Returns true when the SQL statement is a PL/SQL block.
+    pub fn is_plsql(&self) -> bool {
+        self.stmt.is_plsql()
+    }
+
+    /// Returns true when the SQL statement is DDL (data definition language).
+    pub fn is_ddl(&self) -> bool {
+        self.stmt.is_ddl()
+    }
+
+    /// Returns true when the SQL statement is DML (data manipulation language).
+    pub fn is_dml(&self) -> bool {
+        self.stmt.is_dml()
+    }
+
+    /// Returns true when the SQL statement has a `RETURNING INTO` clause.
+    pub fn is_returning(&self) -> bool {
+        self.stmt.is_returning()
+    }
+}
+
+impl fmt::Debug for OwnedStatement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "OwnedStatement {{ stmt: {:?} }}", self.stmt)
+    }
+}
+
 /// Column information in a select statement
 ///
 /// # Examples
@@ -869,6 +2223,12 @@ pub struct ColumnInfo {
     name: String,
     oracle_type: OracleType,
     nullable: bool,
+    db_size_in_bytes: u32,
+    client_size_in_bytes: u32,
+    size_in_chars: u32,
+    precision: i16,
+    scale: i8,
+    fs_precision: u8,
 }
 
 impl ColumnInfo {
@@ -882,9 +2242,37 @@ impl ColumnInfo {
             name: to_rust_str(info.name, info.nameLength),
             oracle_type: OracleType::from_type_info(stmt.conn.ctxt, &info.typeInfo)?,
             nullable: info.nullOk != 0,
+            db_size_in_bytes: info.typeInfo.dbSizeInBytes,
+            client_size_in_bytes: info.typeInfo.clientSizeInBytes,
+            size_in_chars: info.typeInfo.sizeInChars,
+            precision: info.typeInfo.precision,
+            scale: info.typeInfo.scale,
+            fs_precision: info.typeInfo.fsPrecision,
         })
     }
 
+    // Returns the data type to define the column when LOBs are fetched inline.
+    fn inline_lob_type(&self) -> Option<OracleType> {
+        match self.oracle_type {
+            // NCLOB isn't included because the long type has no national character set form.
+            OracleType::CLOB => Some(OracleType::Long),
+            OracleType::BLOB => Some(OracleType::LongRaw),
+            _ => None,
+        }
+    }
+
+    // Returns the data type to define the column by default.
+    fn define_type(&self) -> OracleType {
+        match self.oracle_type {
+            // When the column type is number whose prec is less than 18
+            // and the scale is zero, define it as int64.
+            OracleType::Number(prec, 0) if 0 < prec && prec < DPI_MAX_INT64_PRECISION as u8 => {
+                OracleType::Int64
+            }
+            ref oratype => oratype.clone(),
+        }
+    }
+
     /// Gets column name
     pub fn name(&self) -> &str {
         &self.name
@@ -900,6 +2288,95 @@ impl ColumnInfo {
     pub fn nullable(&self) -> bool {
         self.nullable
     }
+
+    /// Gets the SQL type name without size, precision and scale such as
+    /// `VARCHAR2`, `NUMBER` and `TIMESTAMP WITH TIME ZONE`.
+    ///
+    /// It is `schema.type_name` for object types. Use `oracle_type().to_string()`
+    /// to get the type name with size, precision and scale.
+    pub fn db_type_name(&self) -> String {
+        let name = match self.oracle_type {
+            OracleType::Varchar2(_) => "VARCHAR2",
+            OracleType::NVarchar2(_) => "NVARCHAR2",
+            OracleType::Char(_) => "CHAR",
+            OracleType::NChar(_) => "NCHAR",
+            OracleType::Rowid => "ROWID",
+            OracleType::Raw(_) => "RAW",
+            OracleType::BinaryFloat => "BINARY_FLOAT",
+            OracleType::BinaryDouble => "BINARY_DOUBLE",
+            OracleType::Number(_, _) => "NUMBER",
+            OracleType::Float(_) => "FLOAT",
+            OracleType::Date => "DATE",
+            OracleType::Timestamp(_) => "TIMESTAMP",
+            OracleType::TimestampTZ(_) => "TIMESTAMP WITH TIME ZONE",
+            OracleType::TimestampLTZ(_) => "TIMESTAMP WITH LOCAL TIME ZONE",
+            OracleType::IntervalDS(_, _) => "INTERVAL DAY TO SECOND",
+            OracleType::IntervalYM(_) => "INTERVAL YEAR TO MONTH",
+            OracleType::CLOB => "CLOB",
+            OracleType::NCLOB => "NCLOB",
+            OracleType::BLOB => "BLOB",
+            OracleType::BFILE => "BFILE",
+            OracleType::RefCursor => "REF CURSOR",
+            OracleType::Boolean => "BOOLEAN",
+            OracleType::Object(ref objtype) => {
+                return format!("{}.{}", objtype.schema(), objtype.name())
+            }
+            OracleType::Long => "LONG",
+            OracleType::LongRaw => "LONG RAW",
+            // integer types used internally to fetch numbers
+            OracleType::Int64 | OracleType::UInt64 => "NUMBER",
+        };
+        name.to_string()
+    }
+
+    /// Gets the size of the column in the database in bytes.
+    /// This is zero for types whose size isn't variable such as `NUMBER` and `DATE`.
+    pub fn db_size_in_bytes(&self) -> u32 {
+        self.db_size_in_bytes
+    }
+
+    /// Gets the size of the column in the client character set in bytes.
+    pub fn client_size_in_bytes(&self) -> u32 {
+        self.client_size_in_bytes
+    }
+
+    /// Gets the size of the column in characters.
+    /// This is zero for types other than character types.
+    pub fn size_in_chars(&self) -> u32 {
+        self.size_in_chars
+    }
+
+    /// Gets the precision of `NUMBER`, `FLOAT` and interval types.
+    pub fn precision(&self) -> i16 {
+        self.precision
+    }
+
+    /// Gets the scale of `NUMBER`. This is -127 for `FLOAT`.
+    pub fn scale(&self) -> i8 {
+        self.scale
+    }
+
+    /// Gets the fractional seconds precision of timestamp and interval types.
+    pub fn fs_precision(&self) -> u8 {
+        self.fs_precision
+    }
+
+    /// Gets whether the column uses the national character set,
+    /// that is, the type is `NCHAR`, `NVARCHAR2` or `NCLOB`.
+    pub fn is_nchar(&self) -> bool {
+        match self.oracle_type {
+            OracleType::NChar(_) | OracleType::NVarchar2(_) | OracleType::NCLOB => true,
+            _ => false,
+        }
+    }
+
+    /// Gets the object type when the column is an object or a collection.
+    pub fn object_type(&self) -> Option<&ObjectType> {
+        match self.oracle_type {
+            OracleType::Object(ref objtype) => Some(objtype),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for ColumnInfo {
@@ -954,6 +2431,235 @@ impl<'a> BindIndex for &'a str {
     }
 }
 
+/// A trait implemented by types that can be bound to a statement as parameters.
+///
+/// This is implemented for the following types.
+///
+/// * `()`: no parameters
+/// * `[&T]` and `[&T; N]` where `T: ToSql`, including `&dyn ToSql`:
+///   positional parameters
+/// * `[(&str, &T)]` and `[(&str, &T); N]` where `T: ToSql`, including
+///   `&dyn ToSql`: named parameters
+/// * tuples of `ToSql` types up to 32 elements: positional parameters
+/// * `HashMap<&str, T>` where `T: ToSql`: named parameters
+/// * references to the above types
+///
+/// When `derive` feature is enabled, `#[derive(BindParams)]` is available
+/// for structs whose fields are bound as named parameters. The bind
+/// variable name is the field name or the name specified by
+/// `#[oracle(rename = "...")]`.
+///
+/// The bind variable names are compared case-insensitively.
+///
+/// See [StatementBuilder.query][].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use oracle::*; use std::collections::HashMap; fn try_main() -> Result<()> {
+/// let conn = Connection::connect("scott", "tiger", "")?;
+/// let sql = "select ename from emp where deptno = :deptno and sal >= :sal";
+///
+/// // positional parameters by an array
+/// let enames = conn.statement(sql).query::<String, _>(&[&10, &1000])?;
+/// // positional parameters by a tuple
+/// let enames = conn.statement(sql).query::<String, _>((10, 1000))?;
+/// // named parameters by an array
+/// let enames = conn
+///     .statement(sql)
+///     .query::<String, _>(&[("deptno", &10), ("sal", &1000)])?;
+/// // named parameters by a hash map
+/// let mut params = HashMap::new();
+/// params.insert("deptno", 10);
+/// params.insert("sal", 1000);
+/// let enames = conn.statement(sql).query::<String, _>(&params)?;
+/// # Ok(())} fn main() { try_main().unwrap(); }
+/// ```
+///
+/// [StatementBuilder.query]: struct.StatementBuilder.html#method.query
+pub trait BindParams {
+    /// Binds `self` to the statement.
+    fn bind(&self, stmt: &mut Statement) -> Result<()>;
+}
+
+impl<'a, P: BindParams + ?Sized> BindParams for &'a P {
+    fn bind(&self, stmt: &mut Statement) -> Result<()> {
+        (**self).bind(stmt)
+    }
+}
+
+impl BindParams for () {
+    fn bind(&self, _stmt: &mut Statement) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a, T: ToSql> BindParams for [&'a T] {
+    fn bind(&self, stmt: &mut Statement) -> Result<()> {
+        for (idx, value) in self.iter().enumerate() {
+            stmt.bind(idx + 1, *value)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, 'b> BindParams for [&'a (dyn ToSql + 'b)] {
+    fn bind(&self, stmt: &mut Statement) -> Result<()> {
+        for (idx, value) in self.iter().enumerate() {
+            stmt.bind(idx + 1, *value)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, 'b, T: ToSql> BindParams for [(&'a str, &'b T)] {
+    fn bind(&self, stmt: &mut Statement) -> Result<()> {
+        for &(name, value) in self {
+            stmt.bind(name, value)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, 'b, 'c> BindParams for [(&'a str, &'b (dyn ToSql + 'c))] {
+    fn bind(&self, stmt: &mut Statement) -> Result<()> {
+        for &(name, value) in self {
+            stmt.bind(name, value)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, T: ToSql, S: BuildHasher> BindParams for HashMap<&'a str, T, S> {
+    fn bind(&self, stmt: &mut Statement) -> Result<()> {
+        for (name, value) in self {
+            stmt.bind(*name, value)?;
+        }
+        Ok(())
+    }
+}
+
+macro_rules! impl_bind_params_for_array {
+    ($($N:expr)+) => {
+        $(
+            impl<'a, T: ToSql> BindParams for [&'a T; $N] {
+                fn bind(&self, stmt: &mut Statement) -> Result<()> {
+                    self[..].bind(stmt)
+                }
+            }
+
+            impl<'a, 'b> BindParams for [&'a (dyn ToSql + 'b); $N] {
+                fn bind(&self, stmt: &mut Statement) -> Result<()> {
+                    self[..].bind(stmt)
+                }
+            }
+
+            impl<'a, 'b, T: ToSql> BindParams for [(&'a str, &'b T); $N] {
+                fn bind(&self, stmt: &mut Statement) -> Result<()> {
+                    self[..].bind(stmt)
+                }
+            }
+
+            impl<'a, 'b, 'c> BindParams for [(&'a str, &'b (dyn ToSql + 'c)); $N] {
+                fn bind(&self, stmt: &mut Statement) -> Result<()> {
+                    self[..].bind(stmt)
+                }
+            }
+        )+
+    }
+}
+
+impl_bind_params_for_array! {
+    0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16
+    17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+}
+
+macro_rules! impl_bind_params_for_tuple {
+    ($(
+        [$(($idx:tt, $T:ident))+],
+    )+) => {
+        $(
+            impl<$($T:ToSql,)+> BindParams for ($($T,)+) {
+                fn bind(&self, stmt: &mut Statement) -> Result<()> {
+                    $(stmt.bind($idx + 1, &self.$idx)?;)+
+                    Ok(())
+                }
+            }
+        )+
+    }
+}
+
+impl_bind_params_for_tuple! {
+    [(0,T0)],
+    [(0,T0)(1,T1)],
+    [(0,T0)(1,T1)(2,T2)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)(5,T5)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)(5,T5)(6,T6)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)(5,T5)(6,T6)(7,T7)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)(5,T5)(6,T6)(7,T7)(8,T8)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)(5,T5)(6,T6)(7,T7)(8,T8)(9,T9)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)(5,T5)(6,T6)(7,T7)(8,T8)(9,T9)
+     (10,T10)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)(5,T5)(6,T6)(7,T7)(8,T8)(9,T9)
+     (10,T10)(11,T11)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)(5,T5)(6,T6)(7,T7)(8,T8)(9,T9)
+     (10,T10)(11,T11)(12,T12)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)(5,T5)(6,T6)(7,T7)(8,T8)(9,T9)
+     (10,T10)(11,T11)(12,T12)(13,T13)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)(5,T5)(6,T6)(7,T7)(8,T8)(9,T9)
+     (10,T10)(11,T11)(12,T12)(13,T13)(14,T14)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)(5,T5)(6,T6)(7,T7)(8,T8)(9,T9)
+     (10,T10)(11,T11)(12,T12)(13,T13)(14,T14)(15,T15)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)(5,T5)(6,T6)(7,T7)(8,T8)(9,T9)
+     (10,T10)(11,T11)(12,T12)(13,T13)(14,T14)(15,T15)(16,T16)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)(5,T5)(6,T6)(7,T7)(8,T8)(9,T9)
+     (10,T10)(11,T11)(12,T12)(13,T13)(14,T14)(15,T15)(16,T16)(17,T17)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)(5,T5)(6,T6)(7,T7)(8,T8)(9,T9)
+     (10,T10)(11,T11)(12,T12)(13,T13)(14,T14)(15,T15)(16,T16)(17,T17)(18,T18)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)(5,T5)(6,T6)(7,T7)(8,T8)(9,T9)
+     (10,T10)(11,T11)(12,T12)(13,T13)(14,T14)(15,T15)(16,T16)(17,T17)(18,T18)(19,T19)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)(5,T5)(6,T6)(7,T7)(8,T8)(9,T9)
+     (10,T10)(11,T11)(12,T12)(13,T13)(14,T14)(15,T15)(16,T16)(17,T17)(18,T18)(19,T19)
+     (20,T20)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)(5,T5)(6,T6)(7,T7)(8,T8)(9,T9)
+     (10,T10)(11,T11)(12,T12)(13,T13)(14,T14)(15,T15)(16,T16)(17,T17)(18,T18)(19,T19)
+     (20,T20)(21,T21)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)(5,T5)(6,T6)(7,T7)(8,T8)(9,T9)
+     (10,T10)(11,T11)(12,T12)(13,T13)(14,T14)(15,T15)(16,T16)(17,T17)(18,T18)(19,T19)
+     (20,T20)(21,T21)(22,T22)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)(5,T5)(6,T6)(7,T7)(8,T8)(9,T9)
+     (10,T10)(11,T11)(12,T12)(13,T13)(14,T14)(15,T15)(16,T16)(17,T17)(18,T18)(19,T19)
+     (20,T20)(21,T21)(22,T22)(23,T23)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)(5,T5)(6,T6)(7,T7)(8,T8)(9,T9)
+     (10,T10)(11,T11)(12,T12)(13,T13)(14,T14)(15,T15)(16,T16)(17,T17)(18,T18)(19,T19)
+     (20,T20)(21,T21)(22,T22)(23,T23)(24,T24)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)(5,T5)(6,T6)(7,T7)(8,T8)(9,T9)
+     (10,T10)(11,T11)(12,T12)(13,T13)(14,T14)(15,T15)(16,T16)(17,T17)(18,T18)(19,T19)
+     (20,T20)(21,T21)(22,T22)(23,T23)(24,T24)(25,T25)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)(5,T5)(6,T6)(7,T7)(8,T8)(9,T9)
+     (10,T10)(11,T11)(12,T12)(13,T13)(14,T14)(15,T15)(16,T16)(17,T17)(18,T18)(19,T19)
+     (20,T20)(21,T21)(22,T22)(23,T23)(24,T24)(25,T25)(26,T26)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)(5,T5)(6,T6)(7,T7)(8,T8)(9,T9)
+     (10,T10)(11,T11)(12,T12)(13,T13)(14,T14)(15,T15)(16,T16)(17,T17)(18,T18)(19,T19)
+     (20,T20)(21,T21)(22,T22)(23,T23)(24,T24)(25,T25)(26,T26)(27,T27)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)(5,T5)(6,T6)(7,T7)(8,T8)(9,T9)
+     (10,T10)(11,T11)(12,T12)(13,T13)(14,T14)(15,T15)(16,T16)(17,T17)(18,T18)(19,T19)
+     (20,T20)(21,T21)(22,T22)(23,T23)(24,T24)(25,T25)(26,T26)(27,T27)(28,T28)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)(5,T5)(6,T6)(7,T7)(8,T8)(9,T9)
+     (10,T10)(11,T11)(12,T12)(13,T13)(14,T14)(15,T15)(16,T16)(17,T17)(18,T18)(19,T19)
+     (20,T20)(21,T21)(22,T22)(23,T23)(24,T24)(25,T25)(26,T26)(27,T27)(28,T28)(29,T29)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)(5,T5)(6,T6)(7,T7)(8,T8)(9,T9)
+     (10,T10)(11,T11)(12,T12)(13,T13)(14,T14)(15,T15)(16,T16)(17,T17)(18,T18)(19,T19)
+     (20,T20)(21,T21)(22,T22)(23,T23)(24,T24)(25,T25)(26,T26)(27,T27)(28,T28)(29,T29)
+     (30,T30)],
+    [(0,T0)(1,T1)(2,T2)(3,T3)(4,T4)(5,T5)(6,T6)(7,T7)(8,T8)(9,T9)
+     (10,T10)(11,T11)(12,T12)(13,T13)(14,T14)(15,T15)(16,T16)(17,T17)(18,T18)(19,T19)
+     (20,T20)(21,T21)(22,T22)(23,T23)(24,T24)(25,T25)(26,T26)(27,T27)(28,T28)(29,T29)
+     (30,T30)(31,T31)],
+}
+
 /// A trait implemented by types that can index into columns of a row.
 ///
 /// This trait is sealed and cannot be implemented for types outside of the `oracle` crate.
@@ -48,33 +48,31 @@ impl<'a> Scanner<'a> {
         self.char
     }
 
+    /// Reads digits and returns the number. `None` when there are no digits
+    /// or the number overflows.
     pub fn read_digits(&mut self) -> Option<u64> {
-        let mut num = 0;
+        let mut num = 0u64;
         self.ndigits = 0;
-        loop {
-            num = num * 10
-                + match self.char {
-                    Some('0') => 0,
-                    Some('1') => 1,
-                    Some('2') => 2,
-                    Some('3') => 3,
-                    Some('4') => 4,
-                    Some('5') => 5,
-                    Some('6') => 6,
-                    Some('7') => 7,
-                    Some('8') => 8,
-                    Some('9') => 9,
-                    _ => {
-                        if self.ndigits > 0 {
-                            return Some(num);
-                        } else {
-                            return None;
-                        }
-                    }
-                };
+        while let Some(digit) = self.char.and_then(|c| c.to_digit(10)) {
+            num = num.checked_mul(10)?.checked_add(digit as u64)?;
             self.char = self.chars.next();
             self.ndigits += 1;
         }
+        if self.ndigits > 0 {
+            Some(num)
+        } else {
+            None
+        }
+    }
+
+    /// Skips digits and returns whether at least one digit exists.
+    pub fn skip_digits(&mut self) -> bool {
+        self.ndigits = 0;
+        while let Some('0'..='9') = self.char {
+            self.char = self.chars.next();
+            self.ndigits += 1;
+        }
+        self.ndigits > 0
     }
 
     pub fn ndigits(&self) -> u32 {
@@ -92,13 +90,13 @@ pub fn check_number_format(s: &str) -> result::Result<(), ParseOracleTypeError>
     }
 
     // decimal part
-    if s.read_digits().is_none() {
+    if !s.skip_digits() {
         return Err(err());
     }
     // optional fractional part
     if let Some('.') = s.char() {
         s.next();
-        if s.read_digits().is_none() {
+        if !s.skip_digits() {
             return Err(err());
         }
     }
@@ -112,7 +110,7 @@ pub fn check_number_format(s: &str) -> result::Result<(), ParseOracleTypeError>
                 }
                 _ => (),
             }
-            if s.read_digits().is_none() {
+            if !s.skip_digits() {
                 return Err(err());
             }
         }
@@ -207,6 +205,11 @@ mod tests {
         s.next();
         assert_eq!(s.read_digits(), Some(4567890));
         assert_eq!(s.char(), None);
+
+        let mut s = Scanner::new("18446744073709551615");
+        assert_eq!(s.read_digits(), Some(u64::max_value()));
+        let mut s = Scanner::new("18446744073709551616");
+        assert_eq!(s.read_digits(), None);
     }
 
     #[test]
@@ -229,6 +232,10 @@ mod tests {
         assert_eq!(check_number_format("a"), err);
         assert_eq!(check_number_format("0.0"), ok);
         assert_eq!(check_number_format("9.9"), ok);
+        assert_eq!(
+            check_number_format("-12345678901234567890123456789012345678e-130"),
+            ok
+        );
     }
 
     #[test]
@@ -0,0 +1,180 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+#![cfg(feature = "arrow")]
+
+mod common;
+
+use arrow::array::Array;
+use arrow::array::ArrayRef;
+use arrow::array::BinaryArray;
+use arrow::array::Decimal128Array;
+use arrow::array::Int32Array;
+use arrow::array::Int64Array;
+use arrow::array::ListArray;
+use arrow::array::StringArray;
+use arrow::array::TimestampNanosecondArray;
+use arrow::array::TimestampSecondArray;
+use arrow::datatypes::DataType;
+use arrow::datatypes::Int32Type;
+use arrow::datatypes::TimeUnit;
+use arrow::record_batch::RecordBatch;
+use oracle::Error;
+use std::sync::Arc;
+
+#[test]
+fn into_record_batches() {
+    let conn = common::connect().unwrap();
+    let sql = "select cast(level as number(9)) int_col, \
+                      cast(decode(mod(level, 2), 0, null, level + 0.25) as number(10, 2)) num_col, \
+                      to_char(level) str_col, \
+                      hextoraw('0102') raw_col, \
+                      to_date('2012-03-04 05:06:07', 'YYYY-MM-DD HH24:MI:SS') date_col, \
+                      to_timestamp_tz('2012-03-04 05:06:07.123456789 +01:00', \
+                                      'YYYY-MM-DD HH24:MI:SS.FF9 TZH:TZM') tstz_col \
+                 from dual connect by level <= 5";
+    let mut stmt = conn.prepare(sql, &[]).unwrap();
+    let batches = stmt.query(&[]).unwrap().into_record_batches(2).unwrap();
+    let schema = batches.schema();
+    assert_eq!(schema.field(0).data_type(), &DataType::Int64);
+    assert_eq!(schema.field(1).data_type(), &DataType::Decimal128(10, 2));
+    assert_eq!(schema.field(2).data_type(), &DataType::Utf8);
+    assert_eq!(schema.field(3).data_type(), &DataType::Binary);
+    assert_eq!(
+        schema.field(4).data_type(),
+        &DataType::Timestamp(TimeUnit::Second, None)
+    );
+    assert_eq!(
+        schema.field(5).data_type(),
+        &DataType::Timestamp(TimeUnit::Nanosecond, Some("+00:00".into()))
+    );
+
+    let batches = batches.collect::<Result<Vec<_>, Error>>().unwrap();
+    assert_eq!(
+        batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>(),
+        vec![2, 2, 1]
+    );
+
+    let batch = &batches[0];
+    let ints = batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap();
+    assert_eq!(ints.value(0), 1);
+    assert_eq!(ints.value(1), 2);
+
+    let nums = batch
+        .column(1)
+        .as_any()
+        .downcast_ref::<Decimal128Array>()
+        .unwrap();
+    assert_eq!(nums.value(0), 125);
+    assert!(nums.is_null(1));
+
+    let strs = batch
+        .column(2)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    assert_eq!(strs.value(1), "2");
+
+    let raws = batch
+        .column(3)
+        .as_any()
+        .downcast_ref::<BinaryArray>()
+        .unwrap();
+    assert_eq!(raws.value(0), &[1u8, 2][..]);
+
+    // 2012-03-04 05:06:07
+    let dates = batch
+        .column(4)
+        .as_any()
+        .downcast_ref::<TimestampSecondArray>()
+        .unwrap();
+    assert_eq!(dates.value(0), 1330837567);
+
+    // 2012-03-04 04:06:07.123456789 UTC
+    let timestamps = batch
+        .column(5)
+        .as_any()
+        .downcast_ref::<TimestampNanosecondArray>()
+        .unwrap();
+    assert_eq!(timestamps.value(0), 1330833967123456789);
+}
+
+#[test]
+fn into_record_batches_with_zero_batch_size() {
+    let conn = common::connect().unwrap();
+    let mut stmt = conn.prepare("select 1 from dual", &[]).unwrap();
+    let result = stmt.query(&[]).unwrap().into_record_batches(0);
+    match result {
+        Err(Error::OutOfRange(_)) => (),
+        _ => panic!("zero batch size should be rejected"),
+    }
+}
+
+#[test]
+fn insert_record_batch() {
+    let conn = common::connect().unwrap();
+    conn.execute("truncate table TestTempTable", &[]).unwrap();
+    let int_col: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 2, 3]));
+    let string_col: ArrayRef = Arc::new(StringArray::from(vec![
+        Some("one"),
+        None,
+        Some("two"),
+        Some("three"),
+    ]));
+    let batch =
+        RecordBatch::try_from_iter(vec![("IntCol", int_col), ("StringCol", string_col)]).unwrap();
+    let result = conn.insert_record_batch("TestTempTable", &batch).unwrap();
+    assert_eq!(result.rows_inserted(), 3);
+    assert_eq!(result.batch_errors().len(), 1);
+    // ORA-00001: unique constraint violated
+    assert_eq!(result.batch_errors()[0].code(), 1);
+    assert_eq!(result.batch_errors()[0].offset(), 2);
+
+    let rows = conn
+        .query_as::<(i32, Option<String>)>(
+            "select IntCol, StringCol from TestTempTable order by IntCol",
+            &[],
+        )
+        .unwrap()
+        .collect::<Result<Vec<_>, Error>>()
+        .unwrap();
+    assert_eq!(
+        rows,
+        vec![
+            (1, Some("one".to_string())),
+            (2, None),
+            (3, Some("three".to_string())),
+        ]
+    );
+    conn.rollback().unwrap();
+}
+
+#[test]
+fn insert_record_batch_with_unsupported_type() {
+    let conn = common::connect().unwrap();
+    let int_col: ArrayRef = Arc::new(Int32Array::from(vec![1]));
+    let list_col: ArrayRef = Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+        Some(vec![Some(1)]),
+    ]));
+    let batch =
+        RecordBatch::try_from_iter(vec![("IntCol", int_col), ("StringCol", list_col)]).unwrap();
+    match conn.insert_record_batch("TestTempTable", &batch) {
+        Err(Error::InvalidTypeConversion(_, _)) => (),
+        _ => panic!("list arrays should be rejected"),
+    }
+}
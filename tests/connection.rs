@@ -15,7 +15,9 @@
 
 mod common;
 
-use oracle::{ConnStatus, Connector};
+use oracle::{ConnStatus, Connector, Error};
+use std::thread;
+use std::time::Duration;
 
 #[test]
 fn app_context() {
@@ -138,6 +140,49 @@ fn query_row() {
     common::assert_test_string_type(5, &row);
 }
 
+#[test]
+fn parse() {
+    let conn = common::connect().unwrap();
+    assert!(conn
+        .parse("select IntCol from TestStrings")
+        .unwrap()
+        .is_none());
+
+    // ORA-00942: table or view does not exist
+    let err = conn
+        .parse("select IntCol\n  from NoSuchTable")
+        .unwrap()
+        .unwrap();
+    assert_eq!(err.db_error().code(), 942);
+    assert_eq!((err.line(), err.column()), (2, 8));
+
+    // DDL statements are rejected.
+    match conn.parse("drop table TestTempTable") {
+        Err(Error::InvalidOperation(_)) => (),
+        x => panic!("unexpected result: {:?}", x),
+    }
+}
+
+#[test]
+fn cancel_handle() {
+    let conn = common::connect().unwrap();
+    let cancel_handle = conn.cancel_handle();
+    let thread = thread::spawn(move || {
+        thread::sleep(Duration::from_secs(1));
+        cancel_handle.cancel().unwrap();
+    });
+    let sql = "select count(*) from all_objects, all_objects, all_objects";
+    match conn.query_row_as::<i64>(sql, &[]) {
+        Err(Error::Cancelled(ref dberr)) if dberr.code() == 1013 => (),
+        x => panic!("unexpected result: {:?}", x),
+    }
+    thread.join().unwrap();
+    assert_eq!(
+        conn.query_row_as::<i32>("select 1 from dual", &[]).unwrap(),
+        1
+    );
+}
+
 #[test]
 fn status() {
     let conn = common::connect().unwrap();
@@ -0,0 +1,304 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+#![cfg(feature = "derive")]
+
+mod common;
+
+use oracle::sql_type::{FromSql, OracleObject, OracleType, Timestamp, ToSql, ToSqlNull};
+use oracle::{BindParams, Error};
+
+#[derive(Debug, PartialEq, FromSql, ToSql, ToSqlNull)]
+struct CustomerId(i64);
+
+#[derive(Debug, PartialEq, FromSql, ToSql, ToSqlNull)]
+struct Name {
+    value: String,
+}
+
+#[derive(Debug, PartialEq, FromSql, ToSql, ToSqlNull)]
+enum Status {
+    #[oracle(rename = "A")]
+    Active,
+    #[oracle(rename = "I")]
+    Inactive,
+    Deleted,
+}
+
+#[derive(Debug, PartialEq, FromSql, ToSql, ToSqlNull)]
+enum Priority {
+    Low = 1,
+    Middle,
+    High,
+    Urgent = 10,
+}
+
+#[test]
+fn newtype_from_sql() {
+    let conn = common::connect().unwrap();
+
+    test_from_sql!(
+        &conn,
+        "123456789012",
+        &OracleType::Number(0, -127),
+        &CustomerId(123456789012)
+    );
+    test_from_sql!(
+        &conn,
+        "'John'",
+        &OracleType::Char(4),
+        &Name {
+            value: "John".to_string()
+        }
+    );
+    test_from_sql!(
+        &conn,
+        "cast(null as number)",
+        &OracleType::Number(0, -127),
+        &None::<CustomerId>
+    );
+}
+
+#[test]
+fn newtype_to_sql() {
+    let conn = common::connect().unwrap();
+
+    test_to_sql!(
+        &conn,
+        &CustomerId(123456789012),
+        "TO_CHAR(:1)",
+        "123456789012"
+    );
+    test_to_sql!(
+        &conn,
+        &Name {
+            value: "John".to_string()
+        },
+        ":1",
+        "John"
+    );
+    assert_eq!(
+        CustomerId::oratype_for_null(&conn).unwrap(),
+        i64::oratype_for_null(&conn).unwrap()
+    );
+}
+
+#[test]
+fn string_enum_from_sql() {
+    let conn = common::connect().unwrap();
+
+    test_from_sql!(&conn, "'A'", &OracleType::Char(1), &Status::Active);
+    test_from_sql!(&conn, "'I'", &OracleType::Char(1), &Status::Inactive);
+    test_from_sql!(&conn, "'Deleted'", &OracleType::Char(7), &Status::Deleted);
+
+    let row = conn.query_row("select 'X' from dual", &[]).unwrap();
+    match row.get::<usize, Status>(0) {
+        Err(Error::InvalidTypeConversion(from, to)) => {
+            assert_eq!(from, "\"X\"");
+            assert_eq!(to, "Status");
+        }
+        result => panic!("Unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn string_enum_to_sql() {
+    let conn = common::connect().unwrap();
+
+    test_to_sql!(&conn, &Status::Active, ":1", "A");
+    test_to_sql!(&conn, &Status::Inactive, ":1", "I");
+    test_to_sql!(&conn, &Status::Deleted, ":1", "Deleted");
+    assert_eq!(
+        Status::Active.oratype(&conn).unwrap(),
+        OracleType::NVarchar2(1)
+    );
+}
+
+#[test]
+fn integer_enum_from_sql() {
+    let conn = common::connect().unwrap();
+
+    test_from_sql!(&conn, "1", &OracleType::Number(0, -127), &Priority::Low);
+    test_from_sql!(&conn, "2", &OracleType::Number(0, -127), &Priority::Middle);
+    test_from_sql!(&conn, "3", &OracleType::Number(0, -127), &Priority::High);
+    test_from_sql!(&conn, "10", &OracleType::Number(0, -127), &Priority::Urgent);
+
+    let row = conn.query_row("select 4 from dual", &[]).unwrap();
+    match row.get::<usize, Priority>(0) {
+        Err(Error::InvalidTypeConversion(from, to)) => {
+            assert_eq!(from, "4");
+            assert_eq!(to, "Priority");
+        }
+        result => panic!("Unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn integer_enum_to_sql() {
+    let conn = common::connect().unwrap();
+
+    test_to_sql!(&conn, &Priority::Low, "TO_CHAR(:1)", "1");
+    test_to_sql!(&conn, &Priority::Middle, "TO_CHAR(:1)", "2");
+    test_to_sql!(&conn, &Priority::High, "TO_CHAR(:1)", "3");
+    test_to_sql!(&conn, &Priority::Urgent, "TO_CHAR(:1)", "10");
+}
+
+#[derive(Debug, PartialEq, OracleObject)]
+#[oracle(type_name = "UDT_SUBOBJECT")]
+struct SubObject {
+    #[oracle(rename = "SUBNUMBERVALUE")]
+    number_value: i32,
+    #[oracle(rename = "SUBSTRINGVALUE")]
+    string_value: String,
+}
+
+impl SubObject {
+    fn new(number_value: i32, string_value: &str) -> SubObject {
+        SubObject {
+            number_value: number_value,
+            string_value: string_value.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, OracleObject)]
+#[oracle(type_name = "UDT_OBJECT")]
+struct Object {
+    numbervalue: i32,
+    stringvalue: String,
+    datevalue: Timestamp,
+    #[oracle(rename = "SUBOBJECTVALUE")]
+    sub_object: Option<SubObject>,
+    #[oracle(rename = "SUBOBJECTARRAY")]
+    sub_objects: Vec<SubObject>,
+}
+
+#[derive(Debug, OracleObject)]
+#[oracle(type_name = "UDT_SUBOBJECT")]
+struct InvalidName {
+    subnumbervalue: i32,
+    no_such_attr: String,
+}
+
+#[derive(Debug, OracleObject)]
+#[oracle(type_name = "UDT_SUBOBJECT")]
+struct InvalidType {
+    subnumbervalue: Timestamp,
+}
+
+#[test]
+fn object_from_sql() {
+    let conn = common::connect().unwrap();
+    let sql = "select ObjectCol from TestObjects where IntCol = 1";
+    let obj = conn.query_row_as::<Object>(sql, &[]).unwrap();
+    assert_eq!(
+        obj,
+        Object {
+            numbervalue: 1,
+            stringvalue: "First row".to_string(),
+            datevalue: Timestamp::new(2007, 3, 6, 0, 0, 0, 0),
+            sub_object: Some(SubObject::new(11, "Sub object 1")),
+            sub_objects: vec![
+                SubObject::new(5, "first element"),
+                SubObject::new(6, "second element")
+            ],
+        }
+    );
+
+    let sql = "select ObjectCol from TestObjects where IntCol = 2";
+    let obj = conn.query_row_as::<Option<Object>>(sql, &[]).unwrap();
+    assert_eq!(obj, None);
+
+    let sql = "select t.ObjectCol.SubObjectValue from TestObjects t where IntCol = 1";
+    match conn.query_row_as::<InvalidName>(sql, &[]) {
+        Err(Error::InvalidAttributeName(name)) => assert_eq!(name, "NO_SUCH_ATTR"),
+        result => panic!("Unexpected result: {:?}", result),
+    }
+    match conn.query_row_as::<InvalidType>(sql, &[]) {
+        Err(Error::InvalidTypeConversion(_, to)) => assert_eq!(to, "Timestamp"),
+        result => panic!("Unexpected result: {:?}", result),
+    }
+
+    // UDT_OBJECT isn't UDT_SUBOBJECT.
+    let sql = "select ObjectCol from TestObjects where IntCol = 1";
+    match conn.query_row_as::<SubObject>(sql, &[]) {
+        Err(Error::InvalidTypeConversion(_, to)) => assert_eq!(to, "SubObject"),
+        result => panic!("Unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn object_to_sql() {
+    let conn = common::connect().unwrap();
+    let mut stmt = conn.prepare("begin :1 := :2; end;", &[]).unwrap();
+    let obj = Object {
+        numbervalue: 10,
+        stringvalue: "Ten".to_string(),
+        datevalue: Timestamp::new(2019, 12, 1, 2, 3, 4, 0),
+        sub_object: None,
+        sub_objects: vec![SubObject::new(1, "one"), SubObject::new(2, "two")],
+    };
+    stmt.execute(&[&Object::oratype_for_null(&conn).unwrap(), &obj])
+        .unwrap();
+    assert_eq!(stmt.bind_value::<usize, Object>(1).unwrap(), obj);
+
+    let val = InvalidType {
+        subnumbervalue: Timestamp::new(2019, 12, 1, 0, 0, 0, 0),
+    };
+    match val.oratype(&conn) {
+        Err(Error::InvalidTypeConversion(_, to)) => assert_eq!(to, "Timestamp"),
+        result => panic!("Unexpected result: {:?}", result),
+    }
+}
+
+#[derive(BindParams)]
+struct IntRange {
+    low: i32,
+    #[oracle(rename = "HIGH")]
+    upper: i32,
+}
+
+#[derive(BindParams)]
+struct Named<'a> {
+    int_col: i32,
+    string_col: Option<&'a str>,
+}
+
+#[test]
+fn bind_params() {
+    let conn = common::connect().unwrap();
+    let sql = "select IntCol from TestStrings where IntCol between :low and :high order by IntCol";
+    let range = IntRange { low: 3, upper: 5 };
+    let rows = conn
+        .statement(sql)
+        .query::<i32, _>(&range)
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(rows, vec![3, 4, 5]);
+
+    conn.execute("truncate table TestTempTable", &[]).unwrap();
+    let sql = "insert into TestTempTable values (:int_col, :string_col)";
+    let values = Named {
+        int_col: 1,
+        string_col: Some("one"),
+    };
+    conn.statement(sql).execute(&values).unwrap();
+    let row = conn
+        .statement("select IntCol, StringCol from TestTempTable")
+        .query_row::<(i32, Option<String>), _>(())
+        .unwrap();
+    assert_eq!(row, (1, Some("one".to_string())));
+    conn.rollback().unwrap();
+}
@@ -15,7 +15,7 @@
 
 mod common;
 
-use oracle::sql_type::{IntervalDS, IntervalYM, OracleType, Timestamp};
+use oracle::sql_type::{IntervalDS, IntervalYM, OracleNumber, OracleType, Timestamp};
 use oracle::Error;
 
 macro_rules! chk_num_from {
@@ -331,6 +331,67 @@ fn numeric_to_sql() {
     chk_num_to!(stmt, usize);
 }
 
+#[test]
+fn int128_from_to_sql() {
+    let conn = common::connect().unwrap();
+
+    let val: i128 = 12345678901234567890123456789012345678;
+    test_from_sql!(
+        &conn,
+        "12345678901234567890123456789012345678",
+        &OracleType::Number(0, -127),
+        &val
+    );
+    test_from_sql!(
+        &conn,
+        "-12345678901234567890123456789012345678",
+        &OracleType::Number(0, -127),
+        &-val
+    );
+    test_to_sql!(
+        &conn,
+        &val,
+        "TO_CHAR(:1)",
+        "12345678901234567890123456789012345678"
+    );
+    test_to_sql!(
+        &conn,
+        &-val,
+        "TO_CHAR(:1)",
+        "-12345678901234567890123456789012345678"
+    );
+
+    let val: u128 = 99999999999999999999999999999999999999;
+    test_from_sql!(
+        &conn,
+        "99999999999999999999999999999999999999",
+        &OracleType::Number(0, -127),
+        &val
+    );
+    test_to_sql!(
+        &conn,
+        &val,
+        "TO_CHAR(:1)",
+        "99999999999999999999999999999999999999"
+    );
+
+    // i128 and u128 in a tuple
+    let row = conn
+        .query_row_as::<(i128, u128)>("select -power(10, 37), power(10, 37) from dual", &[])
+        .unwrap();
+    assert_eq!(row, (-10i128.pow(37), 10u128.pow(37)));
+
+    // overflow
+    match conn.query_row_as::<i128>("select power(10, 39) from dual", &[]) {
+        Err(Error::OutOfRange(_)) => (),
+        result => panic!("Unexpected result: {:?}", result),
+    }
+    match conn.query_row_as::<u128>("select -1 from dual", &[]) {
+        Err(Error::OutOfRange(_)) => (),
+        result => panic!("Unexpected result: {:?}", result),
+    }
+}
+
 #[test]
 fn raw_from_to_sql() {
     let conn = common::connect().unwrap();
@@ -678,6 +739,54 @@ fn interval_ym_to_sql() {
     test_to_sql!(&conn, &it, "TO_CHAR(:1)", "-123456789-02");
 }
 
+#[test]
+fn oracle_number_from_sql() {
+    let conn = common::connect().unwrap();
+
+    let num: OracleNumber = "12345678901234567890.123456789012345678".parse().unwrap();
+    test_from_sql!(
+        &conn,
+        "12345678901234567890.123456789012345678",
+        &OracleType::Number(0, -127),
+        &num
+    );
+
+    let num: OracleNumber = "-0.001".parse().unwrap();
+    test_from_sql!(&conn, "-0.001", &OracleType::Number(0, -127), &num);
+
+    let num: OracleNumber = "0.3333333333333333333333333333333333333333"
+        .parse()
+        .unwrap();
+    test_from_sql!(&conn, "1/3", &OracleType::Number(0, -127), &num);
+
+    let num: OracleNumber = "123.45".parse().unwrap();
+    test_from_sql!(&conn, "'123.45'", &OracleType::Char(6), &num);
+}
+
+#[test]
+fn oracle_number_to_sql() {
+    let conn = common::connect().unwrap();
+
+    let num: OracleNumber = "12345678901234567890.123456789012345678".parse().unwrap();
+    test_to_sql!(
+        &conn,
+        &num,
+        "TO_CHAR(:1)",
+        "12345678901234567890.123456789012345678"
+    );
+
+    let num: OracleNumber = "-0.001".parse().unwrap();
+    test_to_sql!(&conn, &num, "TO_CHAR(:1)", "-.001");
+
+    let num = OracleNumber::from(1) / OracleNumber::from(3) * OracleNumber::from(3);
+    test_to_sql!(
+        &conn,
+        &num,
+        "TO_CHAR(:1)",
+        ".9999999999999999999999999999999999999999"
+    );
+}
+
 #[cfg(feature = "chrono")]
 mod chrono {
     use super::common;
@@ -1119,3 +1228,420 @@ mod chrono {
         }
     }
 }
+
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal {
+    use super::common;
+    use super::test_from_sql;
+    use super::test_to_sql;
+    use oracle::sql_type::OracleType;
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn decimal_from_sql() {
+        let conn = common::connect().unwrap();
+
+        let num: Decimal = "1234567890123456789.0123456789".parse().unwrap();
+        test_from_sql!(
+            &conn,
+            "1234567890123456789.0123456789",
+            &OracleType::Number(0, -127),
+            &num
+        );
+
+        let num: Decimal = "-0.001".parse().unwrap();
+        test_from_sql!(&conn, "-0.001", &OracleType::Number(0, -127), &num);
+    }
+
+    #[test]
+    fn decimal_to_sql() {
+        let conn = common::connect().unwrap();
+
+        let num: Decimal = "1234567890123456789.0123456789".parse().unwrap();
+        test_to_sql!(&conn, &num, "TO_CHAR(:1)", "1234567890123456789.0123456789");
+
+        let num: Decimal = "-0.001".parse().unwrap();
+        test_to_sql!(&conn, &num, "TO_CHAR(:1)", "-.001");
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal {
+    use super::common;
+    use super::test_from_sql;
+    use super::test_to_sql;
+    use bigdecimal::BigDecimal;
+    use oracle::sql_type::OracleType;
+
+    #[test]
+    fn bigdecimal_from_sql() {
+        let conn = common::connect().unwrap();
+
+        let num: BigDecimal = "12345678901234567890.123456789012345678".parse().unwrap();
+        test_from_sql!(
+            &conn,
+            "12345678901234567890.123456789012345678",
+            &OracleType::Number(0, -127),
+            &num
+        );
+
+        let num: BigDecimal = "-0.001".parse().unwrap();
+        test_from_sql!(&conn, "-0.001", &OracleType::Number(0, -127), &num);
+    }
+
+    #[test]
+    fn bigdecimal_to_sql() {
+        let conn = common::connect().unwrap();
+
+        let num: BigDecimal = "12345678901234567890.123456789012345678".parse().unwrap();
+        test_to_sql!(
+            &conn,
+            &num,
+            "TO_CHAR(:1)",
+            "12345678901234567890.123456789012345678"
+        );
+
+        let num: BigDecimal = "-0.001".parse().unwrap();
+        test_to_sql!(&conn, &num, "TO_CHAR(:1)", "-.001");
+    }
+}
+
+#[cfg(feature = "time")]
+mod time {
+    use super::common;
+    use super::test_from_sql;
+    use super::test_to_sql;
+    use oracle::sql_type::OracleType;
+    use time::Date;
+    use time::Duration;
+    use time::OffsetDateTime;
+    use time::PrimitiveDateTime;
+    use time::Time;
+    use time::UtcOffset;
+
+    fn datetime(nanosecond: u32) -> PrimitiveDateTime {
+        PrimitiveDateTime::new(
+            Date::try_from_ymd(2012, 3, 4).unwrap(),
+            Time::try_from_hms_nano(5, 6, 7, nanosecond).unwrap(),
+        )
+    }
+
+    //
+    // time::OffsetDateTime
+    //
+
+    #[test]
+    fn offset_datetime_from_sql() {
+        let conn = common::connect().unwrap();
+
+        // DATE -> OffsetDateTime  TZ is '+00:00'.
+        let dttm = datetime(0).assume_utc();
+        test_from_sql!(
+            &conn,
+            "TO_DATE('2012-03-04 05:06:07', 'YYYY-MM-DD HH24:MI:SS')",
+            &OracleType::Date,
+            &dttm
+        );
+
+        // TIMESTAMP -> OffsetDateTime  TZ is '+00:00'.
+        let dttm = datetime(123456789).assume_utc();
+        test_from_sql!(
+            &conn,
+            "TO_TIMESTAMP('2012-03-04 05:06:07.123456789', 'YYYY-MM-DD HH24:MI:SS.FF9')",
+            &OracleType::Timestamp(9),
+            &dttm
+        );
+
+        // TIMESTAMP WITH TIME ZONE -> OffsetDateTime  TZ is set.
+        let dttm = datetime(123456789).assume_offset(UtcOffset::hours(1));
+        test_from_sql!(&conn,
+                       "TO_TIMESTAMP_TZ('2012-03-04 05:06:07.123456789 +01:00', 'YYYY-MM-DD HH24:MI:SS.FF9 TZH:TZM')",
+                       &OracleType::TimestampTZ(9), &dttm);
+    }
+
+    #[test]
+    fn offset_datetime_to_sql() {
+        let conn = common::connect().unwrap();
+
+        // OffsetDateTime -> TIMESTAMP WITH TIME ZONE
+        let dttm = datetime(123456789).assume_utc();
+        test_to_sql!(
+            &conn,
+            &dttm,
+            "TO_CHAR(:1, 'YYYY-MM-DD HH24:MI:SS.FF9 TZH:TZM')",
+            "2012-03-04 05:06:07.123456789 +00:00"
+        );
+
+        let dttm: OffsetDateTime = datetime(123456789).assume_offset(UtcOffset::hours(1));
+        test_to_sql!(
+            &conn,
+            &dttm,
+            "TO_CHAR(:1, 'YYYY-MM-DD HH24:MI:SS.FF9 TZH:TZM')",
+            "2012-03-04 05:06:07.123456789 +01:00"
+        );
+    }
+
+    //
+    // time::PrimitiveDateTime
+    //
+
+    #[test]
+    fn primitive_datetime_from_sql() {
+        let conn = common::connect().unwrap();
+
+        // DATE -> PrimitiveDateTime
+        let dttm = datetime(0);
+        test_from_sql!(
+            &conn,
+            "TO_DATE('2012-03-04 05:06:07', 'YYYY-MM-DD HH24:MI:SS')",
+            &OracleType::Date,
+            &dttm
+        );
+
+        // TIMESTAMP -> PrimitiveDateTime
+        let dttm = datetime(123456789);
+        test_from_sql!(
+            &conn,
+            "TO_TIMESTAMP('2012-03-04 05:06:07.123456789', 'YYYY-MM-DD HH24:MI:SS.FF9')",
+            &OracleType::Timestamp(9),
+            &dttm
+        );
+
+        // TIMESTAMP WITH TIME ZONE -> PrimitiveDateTime (TZ is ignored.)
+        let dttm = datetime(123456789);
+        test_from_sql!(&conn,
+                       "TO_TIMESTAMP_TZ('2012-03-04 05:06:07.123456789 +01:00', 'YYYY-MM-DD HH24:MI:SS.FF9 TZH:TZM')",
+                       &OracleType::TimestampTZ(9), &dttm);
+    }
+
+    #[test]
+    fn primitive_datetime_to_sql() {
+        let conn = common::connect().unwrap();
+
+        // PrimitiveDateTime -> TIMESTAMP
+        let dttm = datetime(123456789);
+        test_to_sql!(
+            &conn,
+            &dttm,
+            "TO_CHAR(:1, 'YYYY-MM-DD HH24:MI:SS.FF9')",
+            "2012-03-04 05:06:07.123456789"
+        );
+    }
+
+    //
+    // time::Date
+    //
+
+    #[test]
+    fn date_from_sql() {
+        let conn = common::connect().unwrap();
+
+        // DATE -> Date
+        let date = Date::try_from_ymd(2012, 3, 4).unwrap();
+        test_from_sql!(
+            &conn,
+            "TO_DATE('2012-03-04 05:06:07', 'YYYY-MM-DD HH24:MI:SS')",
+            &OracleType::Date,
+            &date
+        );
+
+        // TIMESTAMP -> Date
+        test_from_sql!(
+            &conn,
+            "TO_TIMESTAMP('2012-03-04 05:06:07.123456789', 'YYYY-MM-DD HH24:MI:SS.FF9')",
+            &OracleType::Timestamp(9),
+            &date
+        );
+    }
+
+    #[test]
+    fn date_to_sql() {
+        let conn = common::connect().unwrap();
+
+        // Date -> DATE
+        let date = Date::try_from_ymd(2012, 3, 4).unwrap();
+        test_to_sql!(
+            &conn,
+            &date,
+            "TO_CHAR(:1, 'YYYY-MM-DD HH24:MI:SS')",
+            "2012-03-04 00:00:00"
+        );
+    }
+
+    //
+    // time::Duration
+    //
+
+    #[test]
+    fn duration_from_sql() {
+        let conn = common::connect().unwrap();
+
+        // INTERVAL DAY TO SECOND -> Duration
+        let d = Duration::days(1)
+            + Duration::hours(2)
+            + Duration::minutes(3)
+            + Duration::seconds(4)
+            + Duration::nanoseconds(123456789);
+        test_from_sql!(
+            &conn,
+            "INTERVAL '+1 02:03:04.123456789' DAY TO SECOND(9)",
+            &OracleType::IntervalDS(2, 9),
+            &d
+        );
+        let d = -d;
+        test_from_sql!(
+            &conn,
+            "INTERVAL '-1 02:03:04.123456789' DAY TO SECOND(9)",
+            &OracleType::IntervalDS(2, 9),
+            &d
+        );
+    }
+
+    #[test]
+    fn duration_to_sql() {
+        let conn = common::connect().unwrap();
+
+        // Duration -> INTERVAL DAY TO SECOND
+        let d = Duration::days(1)
+            + Duration::hours(2)
+            + Duration::minutes(3)
+            + Duration::seconds(4)
+            + Duration::nanoseconds(123456789);
+        test_to_sql!(&conn, &d, "TO_CHAR(:1)", "+000000001 02:03:04.123456789");
+
+        let d = -d;
+        test_to_sql!(&conn, &d, "TO_CHAR(:1)", "-000000001 02:03:04.123456789");
+    }
+}
+
+#[cfg(feature = "uuid")]
+mod uuid {
+    use super::common;
+    use super::test_from_sql;
+    use super::test_to_sql;
+    use oracle::sql_type::OracleType;
+    use oracle::Error;
+    use uuid::Uuid;
+
+    #[test]
+    fn uuid_from_sql() {
+        let conn = common::connect().unwrap();
+        let uuid = Uuid::parse_str("936da01f-9abd-4d9d-80c7-02af85c822a8").unwrap();
+
+        // RAW(16) -> Uuid
+        test_from_sql!(
+            &conn,
+            "hextoraw('936DA01F9ABD4D9D80C702AF85C822A8')",
+            &OracleType::Raw(16),
+            &uuid
+        );
+
+        // CHAR -> Uuid
+        test_from_sql!(
+            &conn,
+            "'936da01f-9abd-4d9d-80c7-02af85c822a8'",
+            &OracleType::Char(36),
+            &uuid
+        );
+
+        // Invalid length
+        let result = conn.query_row_as::<Uuid>("select hextoraw('0102') from dual", &[]);
+        match result {
+            Err(Error::ParseError(_)) => (),
+            _ => panic!("Unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn uuid_to_sql() {
+        let conn = common::connect().unwrap();
+        let uuid = Uuid::parse_str("936da01f-9abd-4d9d-80c7-02af85c822a8").unwrap();
+
+        // Uuid -> RAW(16)
+        test_to_sql!(
+            &conn,
+            &uuid,
+            "rawtohex(:1)",
+            "936DA01F9ABD4D9D80C702AF85C822A8"
+        );
+
+        // Uuid -> VARCHAR2
+        let mut stmt = conn.prepare("begin :1 := :2; end;", &[]).unwrap();
+        stmt.execute(&[
+            &OracleType::Varchar2(36),
+            &(&uuid, &OracleType::Varchar2(36)),
+        ])
+        .unwrap();
+        let s: String = stmt.bind_value(1).unwrap();
+        assert_eq!(s, "936da01f-9abd-4d9d-80c7-02af85c822a8");
+    }
+}
+
+#[cfg(feature = "serde_json")]
+mod serde_json {
+    use super::common;
+    use super::test_from_sql;
+    use super::test_to_sql;
+    use oracle::sql_type::Json;
+    use oracle::sql_type::OracleType;
+    use oracle::sql_type::ToSql;
+    use oracle::Error;
+    use serde_json_crate::json;
+    use serde_json_crate::Value;
+
+    #[test]
+    fn json_from_sql() {
+        let conn = common::connect().unwrap();
+
+        // VARCHAR2 -> Value
+        let val = json!({"name": "batch", "retries": [1, 2, 3]});
+        test_from_sql!(
+            &conn,
+            "'{\"name\":\"batch\",\"retries\":[1,2,3]}'",
+            &OracleType::Char(34),
+            &val
+        );
+
+        // CLOB -> Value
+        test_from_sql!(
+            &conn,
+            "to_clob('{\"name\":\"batch\",\"retries\":[1,2,3]}')",
+            &OracleType::CLOB,
+            &val
+        );
+
+        // VARCHAR2 -> Json<T>
+        let val = Json(vec![1, 2, 3]);
+        test_from_sql!(&conn, "'[1,2,3]'", &OracleType::Char(7), &val);
+
+        // Invalid JSON
+        let result = conn.query_row_as::<Value>("select '{' from dual", &[]);
+        match result {
+            Err(Error::ParseError(_)) => (),
+            _ => panic!("Unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn json_to_sql() {
+        let conn = common::connect().unwrap();
+
+        // Value -> CLOB
+        let val = json!({"name": "batch"});
+        assert_eq!(val.oratype(&conn).unwrap(), OracleType::CLOB);
+        test_to_sql!(&conn, &val, ":1", "{\"name\":\"batch\"}");
+
+        // Json<T> -> CLOB
+        let val = Json(vec![1, 2, 3]);
+        test_to_sql!(&conn, &val, ":1", "[1,2,3]");
+
+        // A document longer than 4000 bytes
+        let val = Json(vec![12345; 1000]);
+        let mut stmt = conn
+            .prepare("begin :1 := dbms_lob.getlength(:2); end;", &[])
+            .unwrap();
+        stmt.execute(&[&OracleType::Number(0, 0), &val]).unwrap();
+        let len: usize = stmt.bind_value(1).unwrap();
+        assert_eq!(len, 1000 * 6 + 1);
+    }
+}
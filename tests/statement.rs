@@ -15,8 +15,11 @@
 
 mod common;
 
-use oracle::sql_type::{IntervalDS, Timestamp};
-use oracle::{StatementType, StmtParam};
+use oracle::sql_type::{IntervalDS, OracleType, Timestamp, Value};
+use oracle::{Error, OwnedStatement, Row, StatementType};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
 
 #[test]
 fn statement_type() {
@@ -170,7 +173,7 @@ fn query() {
     let conn = common::connect().unwrap();
     let sql = "select * from TestStrings where IntCol >= :icol order by IntCol";
 
-    let mut stmt = conn.prepare(sql, &[StmtParam::FetchArraySize(3)]).unwrap();
+    let mut stmt = conn.statement(sql).fetch_array_size(3).build().unwrap();
 
     for (idx, row_result) in stmt.query(&[&2]).unwrap().enumerate() {
         let row = row_result.unwrap();
@@ -224,12 +227,649 @@ fn query() {
     }
 }
 
+#[test]
+fn query_by_batch() {
+    let conn = common::connect().unwrap();
+    let sql = "select * from TestStrings where IntCol >= :icol order by IntCol";
+    let mut stmt = conn.statement(sql).fetch_array_size(3).build().unwrap();
+    let mut rows = stmt.query(&[&2]).unwrap();
+
+    let mut idx = 2;
+    let mut num_batches = 0;
+    while let Some(batch) = rows.next_batch().unwrap() {
+        assert_eq!(batch.num_rows(), 3);
+        let int_cols = batch.column::<i32, _>(0).unwrap();
+        let nullable_cols = batch.column::<String, _>("NULLABLECOL").unwrap();
+        for (int_col, nullable_col) in int_cols.zip(nullable_cols) {
+            assert_eq!(int_col.unwrap(), Some(idx));
+            assert_eq!(
+                nullable_col.unwrap(),
+                if idx % 2 == 1 {
+                    Some(format!("Nullable {}", idx))
+                } else {
+                    None
+                }
+            );
+            idx += 1;
+        }
+        num_batches += 1;
+    }
+    assert_eq!(idx, 11);
+    assert_eq!(num_batches, 3);
+    drop(rows);
+
+    // mix next() and next_batch()
+    let mut rows = stmt.query_as::<i32>(&[&2]).unwrap();
+    assert_eq!(rows.next().unwrap().unwrap(), 2);
+    {
+        let batch = rows.next_batch().unwrap().unwrap();
+        let int_cols = batch
+            .column::<i32, _>(0)
+            .unwrap()
+            .map(|val| val.unwrap().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(int_cols, vec![3, 4]);
+    }
+    assert_eq!(rows.next().unwrap().unwrap(), 5);
+}
+
+#[test]
+fn get_ref() {
+    let conn = common::connect().unwrap();
+    let sql = "select * from TestStrings where IntCol in (1, 2) order by IntCol";
+    let rows = conn
+        .query(sql, &[])
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    let row = &rows[0];
+    assert_eq!(row.get_ref::<_, str>(1).unwrap(), "String 1");
+    assert_eq!(row.get_ref::<_, [u8]>("RAWCOL").unwrap(), b"Raw 1");
+    assert_eq!(row.get_ref::<_, str>(4).unwrap(), "Nullable 1");
+    assert_eq!(row.sql_values()[1].as_str().unwrap(), "String 1");
+    assert_eq!(row.sql_values()[2].as_bytes().unwrap(), b"Raw 1");
+
+    let row = &rows[1];
+    match row.get_ref::<_, str>(4) {
+        Err(Error::NullValue) => (),
+        x => panic!("unexpected result: {:?}", x),
+    }
+    // IntCol is fetched as an integer.
+    match row.get_ref::<_, str>(0) {
+        Err(Error::InvalidTypeConversion(_, _)) => (),
+        x => panic!("unexpected result: {:?}", x),
+    }
+    match row.get_ref::<_, [u8]>(1) {
+        Err(Error::InvalidTypeConversion(_, _)) => (),
+        x => panic!("unexpected result: {:?}", x),
+    }
+}
+
+#[test]
+fn owned_rows() {
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+    let conn = common::connect().unwrap();
+    let sql = "select IntCol, StringCol, RawCol, NullableCol, \
+                      IntCol + 0.5, to_date('2012-03-04', 'YYYY-MM-DD') \
+                 from TestStrings where IntCol in (1, 2) order by IntCol";
+    let rows = conn.query(sql, &[]).unwrap().collect_owned().unwrap();
+    assert_send_sync(&rows);
+    assert_eq!(rows.len(), 2);
+    assert_eq!(
+        rows[0].values(),
+        &[
+            Value::Int(1),
+            Value::Str("String 1".to_string()),
+            Value::Bytes(b"Raw 1".to_vec()),
+            Value::Str("Nullable 1".to_string()),
+            Value::Number("1.5".to_string()),
+            Value::Timestamp(Timestamp::new(2012, 3, 4, 0, 0, 0, 0)),
+        ][..]
+    );
+    assert_eq!(rows[1].get("NULLABLECOL").unwrap(), &Value::Null);
+    assert_eq!(rows[1].column_names()[0], "INTCOL");
+
+    // rows are available in another thread.
+    let handle = std::thread::spawn(move || rows[1].get(0).unwrap().clone());
+    assert_eq!(handle.join().unwrap(), Value::Int(2));
+
+    let row = conn
+        .query_row("select rowid from TestStrings where IntCol = 1", &[])
+        .unwrap()
+        .to_owned()
+        .unwrap();
+    match row.get(0).unwrap() {
+        Value::Rowid(rowid) => assert_eq!(rowid.len(), 18),
+        x => panic!("unexpected value: {:?}", x),
+    }
+}
+
+#[test]
+fn owned_statement() {
+    struct Repository {
+        find_string: OwnedStatement,
+    }
+
+    let conn = Arc::new(common::connect().unwrap());
+    let mut repo = Repository {
+        find_string: OwnedStatement::new(
+            &conn,
+            "select StringCol from TestStrings where IntCol = :1",
+            &[],
+        )
+        .unwrap(),
+    };
+    // The statement keeps the connection alive.
+    drop(conn);
+    for i in 1..=3 {
+        let s = repo.find_string.query_row_as::<String>(&[&i]).unwrap();
+        assert_eq!(s, format!("String {}", i));
+    }
+
+    let conn = repo.find_string.connection().clone();
+    let stmt = conn
+        .statement("select IntCol from TestStrings where IntCol >= :icol order by IntCol")
+        .fetch_array_size(2)
+        .build_owned(&conn)
+        .unwrap();
+    let other_conn = Arc::new(common::connect().unwrap());
+    let result = conn
+        .statement("select 1 from dual")
+        .build_owned(&other_conn)
+        .map(|_| ());
+    match result {
+        Err(Error::InvalidOperation(_)) => (),
+        x => panic!("unexpected result: {:?}", x),
+    }
+    drop(repo);
+    drop(conn);
+    let rows = stmt
+        .into_query_as_named::<i32>(&[("icol", &8)])
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(rows, vec![8, 9, 10]);
+}
+
+#[test]
+fn prefetch_in_background() {
+    let conn = Arc::new(common::connect().unwrap());
+    let sql = "select level from dual connect by level <= 1000";
+    let stmt = OwnedStatement::new(&conn, sql, &[]).unwrap();
+    let mut rows = stmt
+        .into_query_as::<Row>(&[])
+        .unwrap()
+        .prefetch_in_background(2)
+        .unwrap();
+    assert_eq!(rows.column_info()[0].name(), "LEVEL");
+    let mut n = 0;
+    for row in &mut rows {
+        n += 1;
+        assert_eq!(row.unwrap().get(0).unwrap(), &Value::Number(n.to_string()));
+    }
+    assert_eq!(n, 1000);
+    assert!(rows.next().is_none());
+
+    // cancellation by the consumer
+    let stmt = OwnedStatement::new(&conn, sql, &[]).unwrap();
+    let mut rows = stmt
+        .into_query_as::<Row>(&[])
+        .unwrap()
+        .prefetch_in_background(1)
+        .unwrap();
+    assert!(rows.next().unwrap().is_ok());
+    rows.cancel();
+    assert!(rows.next().is_none());
+
+    // an error in the worker thread
+    let sql = "select 1 / (10 - level) from dual connect by level <= 20";
+    let stmt = OwnedStatement::new(&conn, sql, &[]).unwrap();
+    let rows = stmt
+        .into_query_as::<Row>(&[])
+        .unwrap()
+        .prefetch_in_background(2)
+        .unwrap()
+        .collect::<Vec<_>>();
+    match rows.last() {
+        Some(Err(Error::OciError(ref dberr))) if dberr.code() == 1476 => (),
+        result => panic!("unexpected result: {:?}", result),
+    }
+
+    // zero depth
+    let stmt = OwnedStatement::new(&conn, sql, &[]).unwrap();
+    let result = stmt
+        .into_query_as::<Row>(&[])
+        .unwrap()
+        .prefetch_in_background(0);
+    match result {
+        Err(Error::InvalidOperation(_)) => (),
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn scrollable_query() {
+    let conn = common::connect().unwrap();
+    let sql = "select IntCol from TestStrings order by IntCol";
+    let mut stmt = conn
+        .statement(sql)
+        .scrollable(true)
+        .fetch_array_size(3)
+        .build()
+        .unwrap();
+    let mut rows = stmt.query_scrollable::<i32>(&[]).unwrap();
+    assert_eq!(rows.current_position().unwrap(), 0);
+    assert_eq!(rows.next().unwrap().unwrap(), 1);
+    assert_eq!(rows.next().unwrap().unwrap(), 2);
+    assert_eq!(rows.scroll_last().unwrap(), Some(10));
+    assert_eq!(rows.current_position().unwrap(), 10);
+    assert!(rows.next().is_none());
+    assert_eq!(rows.scroll_first().unwrap(), Some(1));
+    assert_eq!(rows.scroll_absolute(7).unwrap(), Some(7));
+    assert_eq!(rows.current_position().unwrap(), 7);
+    assert_eq!(rows.scroll_prior().unwrap(), Some(6));
+    assert_eq!(rows.scroll_relative(-4).unwrap(), Some(2));
+    assert_eq!(rows.scroll_relative(3).unwrap(), Some(5));
+    assert_eq!(rows.next().unwrap().unwrap(), 6);
+    assert_eq!(rows.scroll_absolute(11).unwrap(), None);
+    assert_eq!(rows.scroll_relative(-100).unwrap(), None);
+
+    // not prepared with scrollable(true)
+    let mut stmt = conn.prepare(sql, &[]).unwrap();
+    let result = stmt.query_scrollable::<i32>(&[]).map(|_| ());
+    match result {
+        Err(Error::InvalidOperation(_)) => (),
+        x => panic!("unexpected result: {:?}", x),
+    }
+}
+
+#[test]
+fn statement_cache_by_tag() {
+    let conn = common::connect().unwrap();
+    conn.set_stmt_cache_size(10).unwrap();
+
+    let mut stmt = conn.prepare("select 1 from dual", &[]).unwrap();
+    assert!(!stmt.found_in_cache());
+    assert_eq!(stmt.query_row_as::<i32>(&[]).unwrap(), 1);
+    stmt.close_with_tag("test_tag").unwrap();
+
+    // The SQL text is ignored when the tag is found in the cache.
+    let mut stmt = conn
+        .statement("select 2 from dual")
+        .tag("test_tag")
+        .build()
+        .unwrap();
+    assert_eq!(stmt.tag(), "test_tag");
+    assert!(stmt.found_in_cache());
+    assert_eq!(stmt.query_row_as::<i32>(&[]).unwrap(), 1);
+    // The statement is put into the cache again when it is dropped.
+    drop(stmt);
+    let mut stmt = conn
+        .statement("select 3 from dual")
+        .tag("test_tag")
+        .build()
+        .unwrap();
+    assert_eq!(stmt.query_row_as::<i32>(&[]).unwrap(), 1);
+    stmt.close().unwrap();
+
+    // The SQL text is used when the tag isn't found.
+    let mut stmt = conn
+        .statement("select 4 from dual")
+        .tag("unknown_tag")
+        .build()
+        .unwrap();
+    assert!(!stmt.found_in_cache());
+    assert_eq!(stmt.query_row_as::<i32>(&[]).unwrap(), 4);
+    drop(stmt);
+
+    // A statement excluded from the cache isn't found after it is closed.
+    let mut stmt = conn
+        .statement("select 5 from dual")
+        .tag("excluded_tag")
+        .exclude_from_cache(true)
+        .build()
+        .unwrap();
+    assert_eq!(stmt.query_row_as::<i32>(&[]).unwrap(), 5);
+    stmt.close().unwrap();
+    let stmt = conn
+        .statement("select 5 from dual")
+        .tag("excluded_tag")
+        .build()
+        .unwrap();
+    assert!(!stmt.found_in_cache());
+}
+
+#[test]
+fn statement_builder_commit_on_success() {
+    let conn = common::connect().unwrap();
+    conn.execute("truncate table TestTempTable", &[]).unwrap();
+    assert!(!conn.autocommit());
+
+    let mut stmt = conn
+        .statement("insert into TestTempTable(IntCol) values (:1)")
+        .commit_on_success(true)
+        .build()
+        .unwrap();
+    stmt.execute(&[&1]).unwrap();
+    let mut stmt = conn
+        .statement("insert into TestTempTable(IntCol) values (:1)")
+        .build()
+        .unwrap();
+    stmt.execute(&[&2]).unwrap();
+    conn.rollback().unwrap();
+
+    // The first row was committed by commit_on_success(true).
+    let sql = "select IntCol from TestTempTable order by IntCol";
+    let rows = conn
+        .query_as::<i32>(sql, &[])
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(rows, vec![1]);
+    conn.execute("truncate table TestTempTable", &[]).unwrap();
+}
+
+#[test]
+fn statement_builder_call_timeout() {
+    let conn = common::connect().unwrap();
+    if !common::check_oracle_version("statement_builder_call_timeout", &conn, 18, 1) {
+        return;
+    }
+    let mut stmt = conn
+        .statement("select 1 from dual")
+        .call_timeout(Duration::from_secs(10))
+        .build()
+        .unwrap();
+    assert_eq!(stmt.query_row_as::<i32>(&[]).unwrap(), 1);
+
+    let mut stmt = conn
+        .statement("begin dbms_session.sleep(2); end;")
+        .call_timeout(Duration::from_millis(500))
+        .build()
+        .unwrap();
+    assert!(stmt.execute(&[]).is_err());
+}
+
+#[test]
+fn query_by_bind_params() {
+    let conn = common::connect().unwrap();
+    let sql = "select IntCol from TestStrings where IntCol between :low and :high order by IntCol";
+    let expected = vec![3, 4, 5];
+    let query = |rs: oracle::ResultSet<i32>| rs.collect::<Result<Vec<_>, _>>().unwrap();
+
+    let stmt = conn.statement(sql);
+    assert_eq!(query(stmt.query(&[&3, &5]).unwrap()), expected);
+    assert_eq!(query(stmt.query((3, 5)).unwrap()), expected);
+    assert_eq!(
+        query(stmt.query(&[("low", &3), ("high", &5)]).unwrap()),
+        expected
+    );
+    let params: &[&dyn oracle::sql_type::ToSql] = &[&3, &"5"];
+    assert_eq!(query(stmt.query(params).unwrap()), expected);
+    let mut params = HashMap::new();
+    params.insert("LOW", 3);
+    params.insert("high", 5);
+    assert_eq!(query(stmt.query(&params).unwrap()), expected);
+
+    let row = stmt.query_row::<oracle::Row, _>((3, 5)).unwrap();
+    assert_eq!(row.get::<usize, i32>(0).unwrap(), 3);
+    assert_eq!(stmt.query_opt::<i32, _>((3, 5)).unwrap(), Some(3));
+    assert_eq!(stmt.query_opt::<i32, _>((6, 5)).unwrap(), None);
+    match stmt.query_row::<i32, _>((6, 5)) {
+        Err(Error::NoDataFound) => (),
+        x => panic!("unexpected result: {:?}", x),
+    }
+    match stmt.execute((3, 5)) {
+        Err(Error::InvalidOperation(_)) => (),
+        x => panic!("unexpected result: {:?}", x.map(|_| ())),
+    }
+
+    let sql = "select count(*) from dual";
+    assert_eq!(conn.statement(sql).query_row::<i32, _>(()).unwrap(), 1);
+
+    conn.execute("truncate table TestTempTable", &[]).unwrap();
+    let stmt = conn
+        .statement("insert into TestTempTable values (:1, :2)")
+        .execute((1, "one"))
+        .unwrap();
+    assert_eq!(stmt.row_count().unwrap(), 1);
+    conn.rollback().unwrap();
+}
+
+#[test]
+fn describe() {
+    let conn = common::connect().unwrap();
+    let sql = "select IntCol, StringCol from TestStrings where IntCol = :1";
+    let mut stmt = conn.prepare(sql, &[]).unwrap();
+    let names = stmt
+        .describe()
+        .unwrap()
+        .iter()
+        .map(|info| info.name().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["INTCOL", "STRINGCOL"]);
+    // The statement can be executed after describe.
+    assert_eq!(stmt.query_row_as::<i32>(&[&3]).unwrap(), 3);
+
+    let mut stmt = conn
+        .prepare("insert into TestTempTable values (:1, :2)", &[])
+        .unwrap();
+    match stmt.describe() {
+        Err(Error::InvalidOperation(_)) => (),
+        x => panic!("unexpected result: {:?}", x),
+    }
+}
+
+#[test]
+fn column_info() {
+    let conn = common::connect().unwrap();
+    let sql = "select cast('a' as varchar2(10 char)) vc, \
+                      cast(1.5 as number(7,2)) num, \
+                      cast(null as nvarchar2(5)) nvc, \
+                      cast(null as timestamp(3) with time zone) tstz \
+                 from dual";
+    let mut stmt = conn.prepare(sql, &[]).unwrap();
+    let info = stmt.describe().unwrap();
+
+    assert_eq!(info[0].db_type_name(), "VARCHAR2");
+    assert_eq!(info[0].size_in_chars(), 10);
+    assert!(!info[0].is_nchar());
+
+    assert_eq!(info[1].db_type_name(), "NUMBER");
+    assert_eq!(info[1].precision(), 7);
+    assert_eq!(info[1].scale(), 2);
+
+    assert_eq!(info[2].db_type_name(), "NVARCHAR2");
+    assert_eq!(info[2].size_in_chars(), 5);
+    assert!(info[2].is_nchar());
+
+    assert_eq!(info[3].db_type_name(), "TIMESTAMP WITH TIME ZONE");
+    assert_eq!(info[3].fs_precision(), 3);
+    assert!(info[3].object_type().is_none());
+}
+
+#[test]
+fn output_type_handler() {
+    let mut conn = common::connect().unwrap();
+    let sql = "select cast(1.25 as number(10,2)), cast(10 as number(5)), \
+                      to_date('2012-03-04', 'YYYY-MM-DD') \
+                 from dual";
+    conn.set_output_type_handler(|info| match *info.oracle_type() {
+        OracleType::Date => Ok(Some(OracleType::Timestamp(0))),
+        _ => Ok(None),
+    });
+
+    // The statement handler overrides the connection handler.
+    let mut stmt = conn
+        .statement(sql)
+        .output_type_handler(|info| match *info.oracle_type() {
+            OracleType::Number(_, _) => Ok(Some(OracleType::Varchar2(40))),
+            _ => Ok(None),
+        })
+        .build()
+        .unwrap();
+    let row = stmt.query_row(&[]).unwrap();
+    let types = row
+        .sql_values()
+        .iter()
+        .map(|val| val.oracle_type().unwrap().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        types,
+        vec![
+            OracleType::Varchar2(40),
+            OracleType::Varchar2(40),
+            OracleType::Date
+        ]
+    );
+    assert_eq!(row.get::<usize, String>(0).unwrap(), "1.25");
+
+    let row = conn.query_row(sql, &[]).unwrap();
+    let types = row
+        .sql_values()
+        .iter()
+        .map(|val| val.oracle_type().unwrap().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        types,
+        vec![
+            OracleType::Number(10, 2),
+            OracleType::Int64,
+            OracleType::Timestamp(0)
+        ]
+    );
+
+    drop(stmt);
+    conn.clear_output_type_handler();
+    let row = conn.query_row(sql, &[]).unwrap();
+    assert_eq!(
+        row.sql_values()[2].oracle_type().unwrap(),
+        &OracleType::Date
+    );
+}
+
+#[test]
+fn fetch_lobs_inline() {
+    let conn = common::connect().unwrap();
+    let sql = "select to_clob('abc'), to_nclob('def'), to_blob(hextoraw('0102')), \
+                      to_clob(null) \
+                 from dual";
+    let mut stmt = conn
+        .statement(sql)
+        .fetch_lobs_inline(true)
+        .max_lob_size(10)
+        .build()
+        .unwrap();
+    let row = stmt.query_row(&[]).unwrap();
+    let types = row
+        .sql_values()
+        .iter()
+        .map(|val| val.oracle_type().unwrap().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        types,
+        vec![
+            OracleType::Long,
+            OracleType::NCLOB,
+            OracleType::LongRaw,
+            OracleType::Long
+        ]
+    );
+    assert_eq!(row.get::<usize, String>(0).unwrap(), "abc");
+    assert_eq!(row.get::<usize, String>(1).unwrap(), "def");
+    assert_eq!(row.get::<usize, Vec<u8>>(2).unwrap(), vec![1u8, 2]);
+    assert_eq!(row.get::<usize, Option<String>>(3).unwrap(), None);
+    drop(stmt);
+
+    let sql = "select to_clob(rpad('x', 11, 'x')) clobcol from dual";
+    let mut stmt = conn
+        .statement(sql)
+        .fetch_lobs_inline(true)
+        .max_lob_size(10)
+        .build()
+        .unwrap();
+    let result = stmt.query_row_as::<String>(&[]);
+    match result {
+        Err(Error::OutOfRange(ref msg)) if msg.contains("CLOBCOL") => (),
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn max_lob_size() {
+    let mut conn = common::connect().unwrap();
+    let sql = "select to_clob('abcde') clobcol, to_blob(hextoraw('0102030405')) blobcol \
+                 from dual";
+    conn.set_max_lob_size(Some(5));
+    assert_eq!(conn.max_lob_size(), Some(5));
+    let row = conn.query_row_as::<(String, Vec<u8>)>(sql, &[]).unwrap();
+    assert_eq!(row.0, "abcde");
+    assert_eq!(row.1, vec![1u8, 2, 3, 4, 5]);
+
+    // The statement setting overrides the connection setting.
+    let mut stmt = conn.statement(sql).max_lob_size(4).build().unwrap();
+    let row = stmt.query_row(&[]).unwrap();
+    match row.get::<usize, String>(0) {
+        Err(Error::OutOfRange(ref msg)) if msg.contains("CLOBCOL") => (),
+        result => panic!("unexpected result: {:?}", result),
+    }
+    match row.get::<usize, Vec<u8>>(1) {
+        Err(Error::OutOfRange(ref msg)) if msg.contains("BLOBCOL") => (),
+        result => panic!("unexpected result: {:?}", result),
+    }
+    drop(stmt);
+
+    conn.set_max_lob_size(None);
+    let sql = "select to_clob(rpad('x', 100, 'x')) from dual";
+    let row = conn.query_row_as::<String>(sql, &[]).unwrap();
+    assert_eq!(row.len(), 100);
+}
+
+#[test]
+fn fetch_memory_budget() {
+    let conn = common::connect().unwrap();
+    let sql = "select level, rpad('x', 1000, 'x') from dual connect by level <= 1000";
+
+    let mut stmt = conn
+        .statement(sql)
+        .fetch_memory_budget(100 * 1024)
+        .build()
+        .unwrap();
+    assert_eq!(stmt.fetch_array_size(), 100); // the default value before execution
+    let rows = stmt.query_as::<(i32, String)>(&[]).unwrap();
+    assert_eq!(rows.count(), 1000);
+    let size = stmt.fetch_array_size();
+    assert!(0 < size && size < 100, "fetch_array_size: {}", size);
+
+    // at least one row is fetched at a time.
+    let mut stmt = conn.statement(sql).fetch_memory_budget(1).build().unwrap();
+    stmt.query(&[]).unwrap();
+    assert_eq!(stmt.fetch_array_size(), 1);
+
+    let mut stmt = conn
+        .statement(sql)
+        .fetch_memory_budget(10 * 1024 * 1024)
+        .grow_fetch_array_size(true)
+        .build()
+        .unwrap();
+    let mut rows = stmt.query_as::<(i32, String)>(&[]).unwrap();
+    rows.next().unwrap().unwrap();
+    drop(rows);
+    assert_eq!(stmt.fetch_array_size(), 200);
+
+    // the array size starts over when the statement is executed again.
+    stmt.query(&[]).unwrap();
+    assert_eq!(stmt.fetch_array_size(), 100);
+}
+
 #[test]
 fn query_row() {
     let conn = common::connect().unwrap();
     let sql = "select * from TestStrings where IntCol = :icol";
 
-    let mut stmt = conn.prepare(sql, &[StmtParam::FetchArraySize(1)]).unwrap();
+    let mut stmt = conn.statement(sql).fetch_array_size(1).build().unwrap();
 
     let row = stmt.query_row(&[&2]).unwrap();
     common::assert_test_string_row(2, &row);
@@ -259,7 +899,7 @@ fn dml_returning() {
     // update no rows
     stmt.execute(&[&11]).unwrap();
     let updated_int_col: Vec<i32> = stmt.returned_values(2).unwrap();
-    assert_eq!(updated_int_col, vec![]);
+    assert_eq!(updated_int_col, Vec::<i32>::new());
 
     // update one row
     stmt.execute(&[&10]).unwrap();
@@ -275,7 +915,7 @@ fn dml_returning() {
     // update no rows
     stmt.execute(&[&11]).unwrap();
     let updated_int_col: Vec<i32> = stmt.returned_values(2).unwrap();
-    assert_eq!(updated_int_col, vec![]);
+    assert_eq!(updated_int_col, Vec::<i32>::new());
 }
 
 #[test]